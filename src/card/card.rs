@@ -4,7 +4,7 @@ use std::fmt::{Debug, Formatter, Error as FmtError};
 use super::{Suit, Rank};
 
 /// Card.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(missing_docs)]
 pub struct Card {
     pub suit: Suit,
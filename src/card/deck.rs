@@ -0,0 +1,115 @@
+use super::{Suit, Rank, Card, Set, Face, Pile};
+
+/// Builder of decks made of several standard sets, possibly stripped of suits or ranks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck {
+    decks: u8,
+    suits: Vec<Suit>,
+    stripped: Vec<Rank>,
+}
+
+/// Card distinguished by the index of the deck it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub struct Indexed {
+    pub deck: u8,
+    pub card: Card,
+}
+
+impl Deck {
+    /// Creates a standard 52-card deck.
+    pub fn new() -> Self {
+        Deck {
+            decks: 1,
+            suits: Suit::all().collect(),
+            stripped: Vec::new(),
+        }
+    }
+
+    /// Creates a Piquet deck of 32 cards, stripped of twos through sixes.
+    pub fn piquet() -> Self {
+        Deck::new().strip(&[Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six])
+    }
+
+    /// Creates a Euchre deck of 24 cards, stripped of twos through eights.
+    pub fn euchre() -> Self {
+        Deck::piquet().strip(&[Rank::Seven, Rank::Eight])
+    }
+
+    /// Combines a number of decks.
+    pub fn decks(mut self, decks: u8) -> Self {
+        self.decks = decks;
+        self
+    }
+
+    /// Restricts each deck to some suits, e.g. for one-suit or two-suit Spider.
+    pub fn suits(mut self, suits: &[Suit]) -> Self {
+        self.suits = suits.to_vec();
+        self
+    }
+
+    /// Removes some ranks from each deck.
+    pub fn strip(mut self, ranks: &[Rank]) -> Self {
+        self.stripped.extend_from_slice(ranks);
+        self
+    }
+
+    /// Returns the number of cards in the deck.
+    pub fn count(&self) -> usize {
+        self.decks as usize * Set::new().filter(|card| self.includes(card)).count()
+    }
+
+    /// Returns the number of copies of a card in the deck.
+    pub fn copies(&self, card: Card) -> usize {
+        if self.includes(&card) {
+            self.decks as usize
+        } else {
+            0
+        }
+    }
+
+    /// Returns the cards of the deck, each deck in the order of `Set`.
+    pub fn cards(&self) -> Vec<Card> {
+        self.indexed().into_iter().map(|indexed| indexed.card).collect()
+    }
+
+    /// Returns the cards of the deck with the index of the deck each belongs to.
+    pub fn indexed(&self) -> Vec<Indexed> {
+        (0..self.decks)
+            .flat_map(|deck| {
+                Set::new()
+                    .filter(|card| self.includes(card))
+                    .map(move |card| Indexed { deck: deck, card: card })
+            })
+            .collect()
+    }
+
+    /// Returns the deck as a face-down pile.
+    pub fn pile(&self) -> Pile {
+        self.cards().into_iter().map(Face::Down).collect()
+    }
+
+    /// Distinguishes duplicate cards in a layout by assigning each occurrence of a card the next
+    /// deck index, or returns `None` if a card occurs more often than the deck holds it.
+    pub fn index<I>(&self, cards: I) -> Option<Vec<Indexed>> where I: IntoIterator<Item=Card> {
+        let mut seen: Vec<Indexed> = Vec::new();
+        for card in cards {
+            let deck = seen.iter().filter(|indexed| indexed.card == card).count();
+            if deck >= self.copies(card) {
+                return None;
+            }
+            seen.push(Indexed { deck: deck as u8, card: card });
+        }
+        Some(seen)
+    }
+
+    fn includes(&self, card: &Card) -> bool {
+        self.suits.contains(&card.suit) && !self.stripped.contains(&card.rank)
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Deck::new()
+    }
+}
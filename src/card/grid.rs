@@ -0,0 +1,80 @@
+use std::fmt::{Debug, Formatter, Error as FmtError};
+use std::mem;
+
+use super::Face;
+
+/// Grid of cells each holding a face-down or face-up card or a gap.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Grid {
+    columns: usize,
+    cells: Vec<Option<Face>>,
+}
+
+impl Grid {
+    /// Creates a grid of gaps.
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Grid {
+            columns: columns,
+            cells: vec![None; rows * columns],
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.cells.len().checked_div(self.columns).unwrap_or(0)
+    }
+
+    /// Returns the number of columns.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Gets the card in a cell, or `None` for a gap or a cell outside the grid.
+    pub fn get(&self, row: usize, column: usize) -> Option<Face> {
+        if column < self.columns {
+            self.cells.get(row * self.columns + column).cloned().unwrap_or(None)
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the contents of a cell, returning the previous contents.
+    ///
+    /// Panics if the cell is outside the grid.
+    pub fn set(&mut self, row: usize, column: usize, face: Option<Face>) -> Option<Face> {
+        assert!(column < self.columns);
+        mem::replace(&mut self.cells[row * self.columns + column], face)
+    }
+
+    /// Removes the card from a cell, leaving a gap.
+    pub fn take(&mut self, row: usize, column: usize) -> Option<Face> {
+        self.set(row, column, None)
+    }
+
+    /// Returns the cells of a row.
+    pub fn row(&self, row: usize) -> &[Option<Face>] {
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    /// Returns the cells of a row mutably.
+    pub fn row_mut(&mut self, row: usize) -> &mut [Option<Face>] {
+        &mut self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    /// Returns the positions of the gaps as `(row, column)`.
+    pub fn gaps(&self) -> Vec<(usize, usize)> {
+        self.cells.iter()
+            .enumerate()
+            .filter(|&(_, cell)| cell.is_none())
+            .map(|(i, _)| (i / self.columns, i % self.columns))
+            .collect()
+    }
+}
+
+impl Debug for Grid {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        f.debug_list()
+            .entries((0..self.rows()).map(|row| self.row(row)))
+            .finish()
+    }
+}
@@ -1,6 +1,6 @@
 use std::char;
 
-use super::{Suit, Rank, Card, Face};
+use super::{Suit, Rank, Card, Joker, Wild, Face};
 
 /// Unicode suit character.
 impl Into<char> for Suit {
@@ -14,6 +14,18 @@ impl Into<char> for Suit {
     }
 }
 
+impl Suit {
+    /// Returns the ASCII suit letter: H, C, D, S.
+    pub fn ascii(&self) -> char {
+        match *self {
+            Suit::Heart => 'H',
+            Suit::Club => 'C',
+            Suit::Diamond => 'D',
+            Suit::Spade => 'S',
+        }
+    }
+}
+
 /// ASCII rank character: A, 2–9, T, J, Q, K.
 impl Into<char> for Rank {
     fn into(self) -> char {
@@ -56,6 +68,27 @@ impl Into<char> for Card {
     }
 }
 
+/// Unicode joker character.
+impl Into<char> for Joker {
+    fn into(self) -> char {
+        match self {
+            Joker::Red => '🂿',
+            Joker::Black => '🃏',
+            Joker::White => '🃟',
+        }
+    }
+}
+
+/// Unicode card or joker character.
+impl Into<char> for Wild {
+    fn into(self) -> char {
+        match self {
+            Wild::Card(card) => card.into(),
+            Wild::Joker(joker) => joker.into(),
+        }
+    }
+}
+
 /// Unicode suit character and ASCII rank character.
 impl Into<[char; 2]> for Card {
     fn into(self) -> [char; 2] {
@@ -63,6 +96,13 @@ impl Into<[char; 2]> for Card {
     }
 }
 
+impl Card {
+    /// Returns the ASCII rank and suit characters, e.g. `QH`.
+    pub fn ascii(&self) -> [char; 2] {
+        [self.rank.into(), self.suit.ascii()]
+    }
+}
+
 /// Unicode card or card back character.
 impl Into<char> for Face {
     fn into(self) -> char {
@@ -82,3 +122,13 @@ impl Into<[char; 2]> for Face {
         }
     }
 }
+
+impl Face {
+    /// Returns the ASCII rank and suit characters, or `##` if face-down.
+    pub fn ascii(&self) -> [char; 2] {
+        match *self {
+            Face::Down(_) => ['#', '#'],
+            Face::Up(card) => card.ascii(),
+        }
+    }
+}
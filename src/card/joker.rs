@@ -0,0 +1,136 @@
+use super::{Color, Suit, Rank, Card};
+
+/// Joker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Joker {
+    Red,
+    Black,
+    White,
+}
+
+impl Joker {
+    /// Returns the color of the joker, if any.
+    pub fn color(&self) -> Option<Color> {
+        match *self {
+            Joker::Red => Some(Color::Red),
+            Joker::Black => Some(Color::Black),
+            Joker::White => None,
+        }
+    }
+}
+
+/// Card or joker acting as a wild card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Wild {
+    Card(Card),
+    Joker(Joker),
+}
+
+impl Wild {
+    /// Returns true if `self` is a joker.
+    pub fn is_joker(&self) -> bool {
+        match *self {
+            Wild::Joker(_) => true,
+            Wild::Card(_) => false,
+        }
+    }
+
+    /// Returns the card, unless `self` is a joker.
+    pub fn card(&self) -> Option<Card> {
+        match *self {
+            Wild::Card(card) => Some(card),
+            Wild::Joker(_) => None,
+        }
+    }
+
+    /// Returns true if `self` can stand for `card`.
+    pub fn could_be(&self, card: Card) -> bool {
+        self.satisfies(|c| c == card)
+    }
+
+    /// Returns true if `self` can stand for a card of `suit`.
+    pub fn could_be_suit(&self, suit: Suit) -> bool {
+        self.satisfies(|c| c.suit == suit)
+    }
+
+    /// Returns true if `self` can stand for a card of `rank`.
+    pub fn could_be_rank(&self, rank: Rank) -> bool {
+        self.satisfies(|c| c.rank == rank)
+    }
+
+    /// Returns true if `self` can stand for a card satisfying `predicate`. Jokers satisfy any
+    /// predicate.
+    pub fn satisfies<F>(&self, predicate: F) -> bool where F: FnOnce(Card) -> bool {
+        match *self {
+            Wild::Card(card) => predicate(card),
+            Wild::Joker(_) => true,
+        }
+    }
+
+    /// Returns true if `self` can be placed on a foundation building up in suit from ace. Jokers
+    /// in the foundation stand for the cards they replace.
+    pub fn follows_foundation(&self, foundation: &[Wild]) -> bool {
+        match last_card(foundation) {
+            Some((top, jokers)) => {
+                let rank = top.rank as usize + jokers + 1;
+                rank <= Rank::King as usize
+                    && self.satisfies(|card| card.suit == top.suit && card.rank as usize == rank)
+            },
+            None => {
+                let rank = foundation.len() + 1;
+                rank <= Rank::King as usize && self.satisfies(|card| card.rank as usize == rank)
+            },
+        }
+    }
+
+    /// Returns true if `self` can be placed on a tableau pile building down in alternate colors.
+    /// Jokers in the pile stand for the cards they replace. Any card can be placed on an empty
+    /// pile or a pile of only jokers; games apply their own rules for those.
+    pub fn builds_down_alternate(&self, pile: &[Wild]) -> bool {
+        self.builds_down(pile, |card, top, steps| {
+            (card.suit.color() != top.suit.color()) == (steps % 2 == 1)
+        })
+    }
+
+    /// Returns true if `self` can be placed on a tableau pile building down in suit. Jokers in
+    /// the pile stand for the cards they replace. Any card can be placed on an empty pile or a
+    /// pile of only jokers; games apply their own rules for those.
+    pub fn builds_down_in_suit(&self, pile: &[Wild]) -> bool {
+        self.builds_down(pile, |card, top, _| card.suit == top.suit)
+    }
+
+    fn builds_down<F>(&self, pile: &[Wild], suit: F) -> bool where F: Fn(Card, Card, usize) -> bool {
+        match last_card(pile) {
+            Some((top, jokers)) => {
+                let steps = jokers + 1;
+                steps < top.rank as usize && self.satisfies(|card| {
+                    card.rank as usize + steps == top.rank as usize && suit(card, top, steps)
+                })
+            },
+            None => true,
+        }
+    }
+}
+
+/// Returns the topmost card of a pile and the number of jokers above it.
+fn last_card(pile: &[Wild]) -> Option<(Card, usize)> {
+    pile.iter()
+        .rev()
+        .enumerate()
+        .filter_map(|(jokers, wild)| wild.card().map(|card| (card, jokers)))
+        .next()
+}
+
+impl From<Card> for Wild {
+    fn from(card: Card) -> Self {
+        Wild::Card(card)
+    }
+}
+
+impl From<Joker> for Wild {
+    fn from(joker: Joker) -> Self {
+        Wild::Joker(joker)
+    }
+}
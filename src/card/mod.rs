@@ -3,15 +3,26 @@
 pub use self::suit::{Color, Suit};
 pub use self::rank::Rank;
 pub use self::card::Card;
+pub use self::joker::{Joker, Wild};
 pub use self::set::Set;
+pub use self::deck::{Deck, Indexed};
 pub use self::face::Face;
 pub use self::pile::Pile;
+pub use self::grid::Grid;
+pub use self::order::{RankOrder, SuitOrder};
 
 mod suit;
 mod rank;
 mod card;
+mod joker;
 mod set;
+mod deck;
 mod face;
 mod pile;
+mod grid;
+mod order;
 
 mod into_char;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,115 @@
+use std::cmp::Ordering;
+
+use super::{Suit, Rank};
+
+/// Rank ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankOrder {
+    /// Ace below two, as `Rank`'s `Ord`.
+    AceLow,
+
+    /// Ace above king.
+    AceHigh,
+
+    /// Circular ranks starting from a base rank, e.g. Canfield foundations.
+    Wrapping(Rank),
+}
+
+impl RankOrder {
+    /// Returns the value of a rank in this ordering, from 1 for `AceLow` and `Wrapping` or 2 for
+    /// `AceHigh`.
+    pub fn value(&self, rank: Rank) -> u8 {
+        match (*self, rank) {
+            (RankOrder::AceHigh, Rank::Ace) => 14,
+            (RankOrder::Wrapping(base), rank) => (rank as u8 + 13 - base as u8) % 13 + 1,
+            (_, rank) => rank as u8,
+        }
+    }
+
+    /// Compares two ranks in this ordering.
+    pub fn cmp(&self, a: Rank, b: Rank) -> Ordering {
+        self.value(a).cmp(&self.value(b))
+    }
+
+    /// Returns the lowest rank in this ordering.
+    pub fn first(&self) -> Rank {
+        match *self {
+            RankOrder::AceLow => Rank::Ace,
+            RankOrder::AceHigh => Rank::Two,
+            RankOrder::Wrapping(base) => base,
+        }
+    }
+
+    /// Returns the highest rank in this ordering.
+    pub fn last(&self) -> Rank {
+        match *self {
+            RankOrder::AceLow => Rank::King,
+            RankOrder::AceHigh => Rank::Ace,
+            RankOrder::Wrapping(base) => base.pred_wrapping(),
+        }
+    }
+
+    /// Returns the successive rank in this ordering.
+    pub fn succ(&self, rank: Rank) -> Option<Rank> {
+        if rank == self.last() {
+            None
+        } else {
+            Some(rank.succ_wrapping())
+        }
+    }
+
+    /// Returns the predecessive rank in this ordering.
+    pub fn pred(&self, rank: Rank) -> Option<Rank> {
+        if rank == self.first() {
+            None
+        } else {
+            Some(rank.pred_wrapping())
+        }
+    }
+
+    /// Returns all ranks from lowest to highest in this ordering.
+    pub fn ranks(&self) -> Vec<Rank> {
+        let mut ranks: Vec<Rank> = Rank::all().collect();
+        ranks.sort_by(|&a, &b| self.cmp(a, b));
+        ranks
+    }
+}
+
+/// Suit ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuitOrder {
+    suits: [Suit; 4],
+}
+
+impl SuitOrder {
+    /// Creates the bridge ordering: clubs, diamonds, hearts, spades, from lowest to highest.
+    pub fn bridge() -> Self {
+        SuitOrder { suits: [Suit::Club, Suit::Diamond, Suit::Heart, Suit::Spade] }
+    }
+
+    /// Creates an ordering of suits from lowest to highest, or returns `None` if a suit is
+    /// repeated.
+    pub fn custom(suits: [Suit; 4]) -> Option<Self> {
+        if Suit::all().all(|suit| suits.contains(&suit)) {
+            Some(SuitOrder { suits: suits })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of a suit in this ordering, from 0.
+    pub fn value(&self, suit: Suit) -> u8 {
+        // Constructors ensure every suit is present.
+        self.suits.iter().position(|&s| s == suit).unwrap() as u8
+    }
+
+    /// Compares two suits in this ordering.
+    pub fn cmp(&self, a: Suit, b: Suit) -> Ordering {
+        self.value(a).cmp(&self.value(b))
+    }
+
+    /// Returns all suits from lowest to highest in this ordering.
+    pub fn suits(&self) -> [Suit; 4] {
+        self.suits
+    }
+}
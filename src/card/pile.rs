@@ -59,7 +59,12 @@ impl Pile {
 
     /// Shuffles the pile.
     pub fn shuffle(&mut self) {
-        rand::thread_rng().shuffle(&mut self.vec);
+        self.shuffle_with(&mut rand::thread_rng());
+    }
+
+    /// Shuffles the pile using a particular random number generator.
+    pub fn shuffle_with<R: Rng>(&mut self, rng: &mut R) {
+        rng.shuffle(&mut self.vec);
     }
 
     /// Flips the whole pile over, reversing the order and flipping each card.
@@ -103,7 +108,7 @@ impl<'a> IntoIterator for &'a Pile {
     type IntoIter = Iter<'a, Face>;
 
     fn into_iter(self) -> Iter<'a, Face> {
-        self.vec[..].into_iter()
+        self.vec.iter()
     }
 }
 
@@ -1,5 +1,5 @@
 /// Card rank.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[allow(missing_docs)]
 pub enum Rank {
     Ace = 1,
@@ -18,6 +18,14 @@ pub enum Rank {
 }
 
 impl Rank {
+    /// Returns an iterator of all ranks, from `Ace` to `King`.
+    pub fn all() -> impl Iterator<Item=Self> {
+        [
+            Rank::Ace, Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six, Rank::Seven,
+            Rank::Eight, Rank::Nine, Rank::Ten, Rank::Jack, Rank::Queen, Rank::King,
+        ].iter().cloned()
+    }
+
     /// Returns true if `self` is a face card, i.e. `Jack`, `Queen`, `King`.
     pub fn is_face(&self) -> bool {
         match *self {
@@ -63,4 +71,19 @@ impl Rank {
             Rank::Ace => None,
         }
     }
+
+    /// Returns the successive rank, wrapping from `King` to `Ace`.
+    pub fn succ_wrapping(self) -> Self {
+        self.succ().unwrap_or(Rank::Ace)
+    }
+
+    /// Returns the predecessive rank, wrapping from `Ace` to `King`.
+    pub fn pred_wrapping(self) -> Self {
+        self.pred().unwrap_or(Rank::King)
+    }
+
+    /// Returns the rank `n` ranks above, wrapping from `King` to `Ace`.
+    pub fn add_wrapping(self, n: u8) -> Self {
+        (0..n % 13).fold(self, |rank, _| rank.succ_wrapping())
+    }
 }
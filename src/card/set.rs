@@ -1,4 +1,4 @@
-use super::Card;
+use super::{Card, Joker, Wild};
 
 /// Iterator of the standard 52-card set.
 #[derive(Debug, Clone, Copy, Default)]
@@ -11,6 +11,11 @@ impl Set {
     pub fn new() -> Self {
         Set::default()
     }
+
+    /// Returns the remaining cards followed by a red and a black joker.
+    pub fn with_jokers(self) -> impl Iterator<Item=Wild> {
+        self.map(Wild::Card).chain(vec![Wild::Joker(Joker::Red), Wild::Joker(Joker::Black)])
+    }
 }
 
 impl Iterator for Set {
@@ -7,7 +7,7 @@ pub enum Color {
 }
 
 /// Card suit.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(missing_docs)]
 pub enum Suit {
     Heart,
@@ -17,6 +17,11 @@ pub enum Suit {
 }
 
 impl Suit {
+    /// Returns an iterator of all suits, in the order of `Set`.
+    pub fn all() -> impl Iterator<Item=Self> {
+        [Suit::Heart, Suit::Club, Suit::Diamond, Suit::Spade].iter().cloned()
+    }
+
     /// Returns the color of the suit.
     pub fn color(&self) -> Color {
         match *self {
@@ -0,0 +1,278 @@
+mod deck {
+    use card::{Suit, Deck, Indexed};
+
+    #[test]
+    fn count_standard() {
+        assert_eq!(Deck::new().count(), 52);
+        assert_eq!(Deck::new().pile().count(), 52);
+    }
+
+    #[test]
+    fn count_piquet() {
+        assert_eq!(Deck::piquet().count(), 32);
+    }
+
+    #[test]
+    fn count_euchre() {
+        assert_eq!(Deck::euchre().count(), 24);
+    }
+
+    #[test]
+    fn count_two_decks() {
+        assert_eq!(Deck::new().decks(2).count(), 104);
+        assert_eq!(Deck::new().decks(2).cards().len(), 104);
+    }
+
+    #[test]
+    fn count_one_suit() {
+        let deck = Deck::new().suits(&[Suit::Spade]).decks(8);
+        assert_eq!(deck.count(), 104);
+        assert!(deck.cards().iter().all(|card| card.suit == Suit::Spade));
+    }
+
+    #[test]
+    fn copies() {
+        let deck = Deck::piquet().decks(2);
+        assert_eq!(deck.copies(card!(H 7)), 2);
+        assert_eq!(deck.copies(card!(H 6)), 0);
+    }
+
+    #[test]
+    fn index_duplicates() {
+        let deck = Deck::new().decks(2);
+        assert_eq!(deck.index(vec![card!(H A), card!(S K), card!(H A)]), Some(vec![
+            Indexed { deck: 0, card: card!(H A) },
+            Indexed { deck: 0, card: card!(S K) },
+            Indexed { deck: 1, card: card!(H A) },
+        ]));
+    }
+
+    #[test]
+    fn index_too_many() {
+        let deck = Deck::new().decks(2);
+        assert_eq!(deck.index(vec![card!(H A), card!(H A), card!(H A)]), None);
+    }
+
+    #[test]
+    fn index_stripped() {
+        assert_eq!(Deck::euchre().index(vec![card!(C 2)]), None);
+    }
+}
+
+mod joker {
+    use card::{Set, Joker, Wild};
+
+    const RED: Wild = Wild::Joker(Joker::Red);
+
+    #[test]
+    fn could_be_card() {
+        assert!(Wild::Card(card!(H A)).could_be(card!(H A)));
+        assert!(!Wild::Card(card!(H A)).could_be(card!(H 2)));
+        assert!(RED.could_be(card!(S K)));
+    }
+
+    #[test]
+    fn could_be_suit_rank() {
+        assert!(Wild::Card(card!(C 5)).could_be_suit(suit!(C)));
+        assert!(!Wild::Card(card!(C 5)).could_be_suit(suit!(D)));
+        assert!(Wild::Card(card!(C 5)).could_be_rank(rank!(5)));
+        assert!(!Wild::Card(card!(C 5)).could_be_rank(rank!(6)));
+        assert!(RED.could_be_suit(suit!(D)));
+        assert!(RED.could_be_rank(rank!(Q)));
+    }
+
+    #[test]
+    fn satisfies() {
+        assert!(Wild::Card(card!(D 9)).satisfies(|c| c.rank > rank!(8)));
+        assert!(!Wild::Card(card!(D 9)).satisfies(|c| c.rank > rank!(9)));
+        assert!(Wild::Joker(Joker::Black).satisfies(|_| false));
+    }
+
+    #[test]
+    fn into_char() {
+        assert_eq!(Into::<char>::into(Joker::Red), '\u{1F0BF}');
+        assert_eq!(Into::<char>::into(Joker::Black), '\u{1F0CF}');
+        assert_eq!(Into::<char>::into(Joker::White), '\u{1F0DF}');
+        assert_eq!(Into::<char>::into(RED), '\u{1F0BF}');
+        assert_eq!(Into::<char>::into(Wild::Card(card!(S A))), '\u{1F0A1}');
+    }
+
+    #[test]
+    fn set_with_jokers() {
+        let set: Vec<Wild> = Set::new().with_jokers().collect();
+        assert_eq!(set.len(), 54);
+        assert_eq!(set[0], Wild::Card(card!(H A)));
+        assert_eq!(set[51], Wild::Card(card!(S A)));
+        assert_eq!(set[52], Wild::Joker(Joker::Red));
+        assert_eq!(set[53], Wild::Joker(Joker::Black));
+    }
+
+    #[test]
+    fn follows_foundation_empty() {
+        assert!(Wild::Card(card!(H A)).follows_foundation(&[]));
+        assert!(!Wild::Card(card!(H 2)).follows_foundation(&[]));
+        assert!(RED.follows_foundation(&[]));
+    }
+
+    #[test]
+    fn follows_foundation() {
+        let foundation = [Wild::Card(card!(H A)), Wild::Card(card!(H 2))];
+        assert!(Wild::Card(card!(H 3)).follows_foundation(&foundation));
+        assert!(!Wild::Card(card!(D 3)).follows_foundation(&foundation));
+        assert!(!Wild::Card(card!(H 4)).follows_foundation(&foundation));
+        assert!(RED.follows_foundation(&foundation));
+    }
+
+    #[test]
+    fn follows_foundation_joker_top() {
+        let foundation = [Wild::Card(card!(H A)), RED];
+        assert!(Wild::Card(card!(H 3)).follows_foundation(&foundation));
+        assert!(!Wild::Card(card!(H 2)).follows_foundation(&foundation));
+        assert!(Wild::Card(card!(S 3)).follows_foundation(&[RED, RED]));
+    }
+
+    #[test]
+    fn follows_foundation_full() {
+        let mut foundation: Vec<Wild> = Set::new().take(12).map(Wild::Card).collect();
+        foundation.push(RED);
+        assert!(!RED.follows_foundation(&foundation));
+    }
+
+    #[test]
+    fn follows_foundation_long() {
+        assert!(!Wild::Card(card!(H A)).follows_foundation(&[RED; 256]));
+        let mut foundation = vec![Wild::Card(card!(H K))];
+        foundation.extend(vec![RED; 255]);
+        assert!(!Wild::Card(card!(H A)).follows_foundation(&foundation));
+    }
+
+    #[test]
+    fn builds_down_alternate() {
+        let pile = [Wild::Card(card!(S 9))];
+        assert!(Wild::Card(card!(H 8)).builds_down_alternate(&pile));
+        assert!(!Wild::Card(card!(C 8)).builds_down_alternate(&pile));
+        assert!(!Wild::Card(card!(H 7)).builds_down_alternate(&pile));
+        assert!(RED.builds_down_alternate(&pile));
+        assert!(Wild::Card(card!(H 8)).builds_down_alternate(&[]));
+    }
+
+    #[test]
+    fn builds_down_alternate_joker_top() {
+        let pile = [Wild::Card(card!(S 9)), RED];
+        assert!(Wild::Card(card!(C 7)).builds_down_alternate(&pile));
+        assert!(!Wild::Card(card!(D 7)).builds_down_alternate(&pile));
+        assert!(!Wild::Card(card!(C 8)).builds_down_alternate(&pile));
+    }
+
+    #[test]
+    fn builds_down_ace() {
+        assert!(!RED.builds_down_alternate(&[Wild::Card(card!(S A))]));
+    }
+
+    #[test]
+    fn builds_down_long() {
+        let mut pile = vec![Wild::Card(card!(S K))];
+        pile.extend(vec![RED; 256]);
+        assert!(!Wild::Card(card!(H Q)).builds_down_alternate(&pile));
+        assert!(!Wild::Card(card!(S Q)).builds_down_in_suit(&pile));
+    }
+
+    #[test]
+    fn builds_down_in_suit() {
+        let pile = [Wild::Card(card!(D 5)), RED];
+        assert!(Wild::Card(card!(D 3)).builds_down_in_suit(&pile));
+        assert!(!Wild::Card(card!(H 3)).builds_down_in_suit(&pile));
+    }
+}
+
+mod order {
+    use std::cmp::Ordering;
+
+    use card::{Suit, Rank, RankOrder, SuitOrder};
+
+    #[test]
+    fn rank_all() {
+        let ranks: Vec<Rank> = Rank::all().collect();
+        assert_eq!(ranks.len(), 13);
+        assert_eq!(ranks[0], rank!(A));
+        assert_eq!(ranks[12], rank!(K));
+        assert!(ranks.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn suit_all() {
+        let suits: Vec<Suit> = Suit::all().collect();
+        assert_eq!(suits, vec![suit!(H), suit!(C), suit!(D), suit!(S)]);
+    }
+
+    #[test]
+    fn ace_low() {
+        let order = RankOrder::AceLow;
+        assert_eq!(order.cmp(rank!(A), rank!(2)), Ordering::Less);
+        assert_eq!((order.first(), order.last()), (rank!(A), rank!(K)));
+        assert_eq!(order.succ(rank!(K)), None);
+        assert_eq!(order.pred(rank!(A)), None);
+        assert_eq!(order.succ(rank!(Q)), Some(rank!(K)));
+    }
+
+    #[test]
+    fn ace_high() {
+        let order = RankOrder::AceHigh;
+        assert_eq!(order.cmp(rank!(A), rank!(K)), Ordering::Greater);
+        assert_eq!((order.first(), order.last()), (rank!(2), rank!(A)));
+        assert_eq!(order.succ(rank!(K)), Some(rank!(A)));
+        assert_eq!(order.succ(rank!(A)), None);
+        assert_eq!(order.pred(rank!(A)), Some(rank!(K)));
+        assert_eq!(order.pred(rank!(2)), None);
+    }
+
+    #[test]
+    fn wrapping() {
+        let order = RankOrder::Wrapping(rank!(5));
+        assert_eq!(order.value(rank!(5)), 1);
+        assert_eq!(order.value(rank!(4)), 13);
+        assert_eq!(order.value(rank!(A)), 10);
+        assert_eq!(order.cmp(rank!(K), rank!(2)), Ordering::Less);
+        assert_eq!((order.first(), order.last()), (rank!(5), rank!(4)));
+        assert_eq!(order.succ(rank!(K)), Some(rank!(A)));
+        assert_eq!(order.succ(rank!(4)), None);
+        assert_eq!(order.pred(rank!(5)), None);
+        assert_eq!(order.pred(rank!(A)), Some(rank!(K)));
+    }
+
+    #[test]
+    fn wrapping_ace() {
+        let order = RankOrder::Wrapping(rank!(A));
+        assert_eq!(order.ranks(), RankOrder::AceLow.ranks());
+    }
+
+    #[test]
+    fn ranks() {
+        assert_eq!(RankOrder::AceLow.ranks(), Rank::all().collect::<Vec<_>>());
+        let ranks = RankOrder::AceHigh.ranks();
+        assert_eq!((ranks[0], ranks[12]), (rank!(2), rank!(A)));
+        let ranks = RankOrder::Wrapping(rank!(J)).ranks();
+        assert_eq!(&ranks[..4], &[rank!(J), rank!(Q), rank!(K), rank!(A)]);
+        assert_eq!(ranks[12], rank!(10));
+    }
+
+    #[test]
+    fn bridge() {
+        let order = SuitOrder::bridge();
+        assert_eq!(order.value(suit!(C)), 0);
+        assert_eq!(order.value(suit!(S)), 3);
+        assert_eq!(order.cmp(suit!(H), suit!(D)), Ordering::Greater);
+    }
+
+    #[test]
+    fn custom() {
+        let order = SuitOrder::custom([suit!(S), suit!(H), suit!(C), suit!(D)]).unwrap();
+        assert_eq!(order.value(suit!(S)), 0);
+        assert_eq!(order.cmp(suit!(D), suit!(C)), Ordering::Greater);
+    }
+
+    #[test]
+    fn custom_repeated() {
+        assert_eq!(SuitOrder::custom([suit!(H); 4]), None);
+    }
+}
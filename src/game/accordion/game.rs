@@ -0,0 +1,74 @@
+use card::{Card, Face, Set, Pile};
+use game::Game;
+use super::{Accordion, Play};
+
+impl Game for Accordion {
+    type Rules = ();
+    type Play = Play;
+
+    fn new(_: ()) -> Self {
+        Accordion {
+            stock: Set::new().map(Face::Down).collect(),
+            piles: Vec::new(),
+            moved: Vec::new(),
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        while let Some(face) = self.stock.pop() {
+            self.piles.push(Pile { vec: vec![face.flipped()] });
+        }
+    }
+
+    fn is_won(&self) -> bool {
+        self.stock.is_empty() && self.piles.len() == 1
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        let index = play.index as usize;
+        let distance = play.distance as usize;
+        if index < distance || index >= self.piles.len() {
+            return false;
+        }
+        match (self.piles[index].top(), self.piles[index - distance].top()) {
+            (Some(card), Some(dest)) => is_match(card.card(), dest.card()),
+            _ => false,
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        let index = play.index as usize;
+        let pile = self.piles.remove(index);
+        self.moved.push(pile.count());
+        self.piles[index - play.distance as usize].vec.extend(pile.vec);
+    }
+
+    fn undo(&mut self, play: &Play) {
+        let index = play.index as usize;
+        let count = self.moved.pop().unwrap_or(1);
+        let pile = {
+            let dest = &mut self.piles[index - play.distance as usize];
+            let at = dest.count().saturating_sub(count);
+            Pile { vec: dest.vec.split_off(at) }
+        };
+        self.piles.insert(index, pile);
+    }
+}
+
+impl Accordion {
+    /// Returns the number of piles in the line.
+    pub fn len(&self) -> usize {
+        self.piles.len()
+    }
+
+    /// Returns true if no piles have been dealt.
+    pub fn is_empty(&self) -> bool {
+        self.piles.is_empty()
+    }
+}
+
+/// Returns true if a card may be moved onto another, i.e. they share a suit or rank.
+pub fn is_match(card: Card, dest: Card) -> bool {
+    card.suit == dest.suit || card.rank == dest.rank
+}
@@ -0,0 +1,35 @@
+//! Accordion patience.
+
+use card::Pile;
+
+/// Accordion patience game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accordion {
+    stock: Pile,
+    piles: Vec<Pile>,
+    moved: Vec<usize>,
+}
+
+/// Accordion play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    /// Index of the pile to move, counted from the left of the line starting at zero.
+    pub index: u8,
+
+    /// Distance to the pile it is moved onto.
+    pub distance: Distance,
+}
+
+/// Distance leftward to a pile that may be moved onto.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum Distance {
+    One = 1,
+    Three = 3,
+}
+
+mod game;
+mod solver;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use card::{Card, Pile};
+use super::{Accordion, Play, Distance};
+use super::game::is_match;
+
+/// Depth-first search over the top cards of the line, which alone determine the plays
+/// available.
+struct Solver {
+    dead: HashSet<Vec<Card>>,
+    limit: usize,
+    plays: Vec<Play>,
+}
+
+impl Solver {
+    fn search(&mut self, line: &[Card]) -> bool {
+        if line.len() == 1 {
+            return true;
+        }
+        if self.dead.len() >= self.limit || self.dead.contains(line) {
+            return false;
+        }
+
+        for index in 1..line.len() {
+            for &distance in &[Distance::One, Distance::Three] {
+                let dest = match index.checked_sub(distance as usize) {
+                    Some(dest) => dest,
+                    None => continue,
+                };
+                if !is_match(line[index], line[dest]) {
+                    continue;
+                }
+
+                let mut next = line.to_vec();
+                let card = next.remove(index);
+                next[dest] = card;
+                self.plays.push(Play { index: index as u8, distance: distance });
+                if self.search(&next) {
+                    return true;
+                }
+                let _ = self.plays.pop();
+            }
+        }
+
+        let _ = self.dead.insert(line.to_vec());
+        false
+    }
+}
+
+impl Accordion {
+    /// Searches for a sequence of plays that wins the game from its current position.
+    ///
+    /// At most `limit` dead-end positions are explored, so `None` means that no solution was
+    /// found, not necessarily that none exists.
+    pub fn solve(&self, limit: usize) -> Option<Vec<Play>> {
+        let line = self.piles.iter()
+            .filter_map(Pile::top)
+            .map(|face| face.card())
+            .collect::<Vec<_>>();
+        if line.is_empty() {
+            return None;
+        }
+
+        let mut solver = Solver {
+            dead: HashSet::new(),
+            limit: limit,
+            plays: Vec::new(),
+        };
+        if solver.search(&line) {
+            Some(solver.plays)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use card::{Card, Face, Pile};
+use game::Game;
+use game::accordion::Accordion;
+
+fn line(cards: &[Card]) -> Accordion {
+    let mut game = Accordion::new(());
+    game.stock = Pile::new();
+    game.piles = cards.iter().map(|&card| Pile { vec: vec![Face::Up(card)] }).collect();
+    game
+}
+
+mod is_valid {
+    use game::Game;
+    use game::accordion::{Play, Distance};
+    use super::line;
+
+    #[test]
+    fn valid_one_suit() {
+        let game = line(&[card!(H 2), card!(H 9)]);
+        assert!(game.is_valid(&Play { index: 1, distance: Distance::One }));
+    }
+
+    #[test]
+    fn valid_one_rank() {
+        let game = line(&[card!(H 2), card!(S 2)]);
+        assert!(game.is_valid(&Play { index: 1, distance: Distance::One }));
+    }
+
+    #[test]
+    fn valid_three() {
+        let game = line(&[card!(H 2), card!(C 5), card!(D 7), card!(S 2)]);
+        assert!(game.is_valid(&Play { index: 3, distance: Distance::Three }));
+    }
+
+    #[test]
+    fn invalid_two() {
+        let game = line(&[card!(H 2), card!(C 5), card!(S 2)]);
+        assert!(!game.is_valid(&Play { index: 2, distance: Distance::Three }));
+    }
+
+    #[test]
+    fn invalid_non_match() {
+        let game = line(&[card!(H 2), card!(S 9)]);
+        assert!(!game.is_valid(&Play { index: 1, distance: Distance::One }));
+    }
+
+    #[test]
+    fn invalid_leftmost() {
+        let game = line(&[card!(H 2), card!(H 9)]);
+        assert!(!game.is_valid(&Play { index: 0, distance: Distance::One }));
+    }
+
+    #[test]
+    fn invalid_out_of_range() {
+        let game = line(&[card!(H 2), card!(H 9)]);
+        assert!(!game.is_valid(&Play { index: 2, distance: Distance::One }));
+    }
+}
+
+mod play {
+    use card::Face;
+    use game::Game;
+    use game::accordion::{Accordion, Play, Distance};
+    use super::line;
+
+    #[test]
+    fn deal() {
+        let mut game = Accordion::new(());
+        game.deal();
+        assert!(game.stock.is_empty());
+        assert_eq!(52, game.len());
+        assert!(game.piles.iter().all(|pile| pile.count() == 1 && pile.top().unwrap().is_up()));
+    }
+
+    #[test]
+    fn play() {
+        let mut game = line(&[card!(H 2), card!(C 5), card!(D 7), card!(S 2), card!(S 9)]);
+        game.play(&Play { index: 3, distance: Distance::Three });
+        assert_eq!(4, game.len());
+        assert_eq!(2, game.piles[0].count());
+        assert_eq!(Some(Face::Up(card!(S 2))), game.piles[0].top());
+        assert_eq!(Some(Face::Up(card!(S 9))), game.piles[3].top());
+    }
+
+    #[test]
+    fn won() {
+        let mut game = line(&[card!(H 2), card!(H 9)]);
+        game.play(&Play { index: 1, distance: Distance::One });
+        assert!(game.is_won());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::accordion::{Play, Distance};
+    use super::line;
+
+    #[test]
+    fn play() {
+        let before = line(&[card!(H 2), card!(C 5), card!(D 7), card!(S 2), card!(S 9)]);
+        let mut after = before.clone();
+        after.play(&Play { index: 4, distance: Distance::One });
+        after.play(&Play { index: 3, distance: Distance::Three });
+        after.undo(&Play { index: 3, distance: Distance::Three });
+        after.undo(&Play { index: 4, distance: Distance::One });
+        assert_eq!(before, after);
+    }
+}
+
+mod solve {
+    use game::Game;
+    use game::accordion::Accordion;
+    use super::line;
+
+    fn check(mut game: Accordion, limit: usize) -> bool {
+        match game.solve(limit) {
+            Some(plays) => {
+                for play in &plays {
+                    assert!(game.is_valid(play));
+                    game.play(play);
+                }
+                assert!(game.is_won());
+                true
+            },
+            None => false,
+        }
+    }
+
+    #[test]
+    fn solvable() {
+        let game = line(&[card!(H A), card!(H 2), card!(C 2), card!(C 3)]);
+        assert!(check(game, 1000));
+    }
+
+    #[test]
+    fn unsolvable() {
+        let game = line(&[card!(H A), card!(C 2)]);
+        assert!(!check(game, 1000));
+    }
+
+    #[test]
+    fn dealt() {
+        let mut game = Accordion::new(());
+        game.deal();
+        let _ = check(game, 10_000);
+    }
+}
@@ -0,0 +1,96 @@
+use std::cmp::Ordering;
+
+use card::{Rank, RankOrder, Face, Set, Pile};
+use game::Game;
+use super::{AcesUp, Play};
+
+impl Game for AcesUp {
+    type Rules = ();
+    type Play = Play;
+
+    fn new(_: ()) -> Self {
+        AcesUp {
+            stock: Set::new().map(Face::Down).collect(),
+            discard: Pile::new(),
+            tableau: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        self.play(&Play::Deal);
+    }
+
+    fn is_won(&self) -> bool {
+        self.stock.is_empty()
+            && self.discard.count() == 48
+            && self.tableau.iter().all(|pile| {
+                pile.into_iter().all(|face| face.card().rank == Rank::Ace)
+            })
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::Deal => {
+                !self.stock.is_empty()
+            },
+
+            Play::Discard(tableau) => {
+                self.tableau[tableau as usize].top().map_or(false, |face| {
+                    let card = face.card();
+                    self.tableau.iter().filter_map(Pile::top).any(|top| {
+                        let top = top.card();
+                        top.suit == card.suit
+                            && RankOrder::AceHigh.cmp(card.rank, top.rank) == Ordering::Less
+                    })
+                })
+            },
+
+            Play::TableauTableau(src, dest) => {
+                !self.tableau[src as usize].is_empty() && self.tableau[dest as usize].is_empty()
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::Deal => {
+                for pile in &mut self.tableau {
+                    self.stock.deal_to(pile, 1, true);
+                }
+            },
+
+            Play::Discard(tableau) => {
+                self.tableau[tableau as usize].move_to(&mut self.discard, 1);
+            },
+
+            Play::TableauTableau(src, dest) => {
+                if src < dest {
+                    let (left, right) = self.tableau.split_at_mut(dest as usize);
+                    left[src as usize].move_to(&mut right[0], 1);
+                } else {
+                    let (left, right) = self.tableau.split_at_mut(src as usize);
+                    right[0].move_to(&mut left[dest as usize], 1);
+                }
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::Deal => {
+                for pile in self.tableau.iter_mut().rev() {
+                    pile.deal_to(&mut self.stock, 1, true);
+                }
+            },
+
+            Play::Discard(tableau) => {
+                self.discard.move_to(&mut self.tableau[tableau as usize], 1);
+            },
+
+            Play::TableauTableau(src, dest) => {
+                self.play(&Play::TableauTableau(dest, src));
+            },
+        }
+    }
+}
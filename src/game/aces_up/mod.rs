@@ -0,0 +1,39 @@
+//! Aces Up solitaire.
+
+use card::Pile;
+
+/// Aces Up solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcesUp {
+    stock: Pile,
+    discard: Pile,
+    tableau: [Pile; 4],
+}
+
+/// Aces Up play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Deal a card from stock onto each tableau.
+    Deal,
+
+    /// Discard top card of tableau, lower than another top card of its suit.
+    Discard(Tableau),
+
+    /// Move top card of tableau to an empty tableau.
+    TableauTableau(Tableau, Tableau),
+}
+
+/// Tableaux.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Tableau {
+    One,
+    Two,
+    Three,
+    Four,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
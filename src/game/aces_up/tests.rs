@@ -0,0 +1,133 @@
+mod is_valid {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::aces_up::{AcesUp, Play, Tableau};
+
+    #[test]
+    fn valid_deal() {
+        let game = AcesUp::new(());
+        assert!(game.is_valid(&Play::Deal));
+    }
+
+    #[test]
+    fn invalid_deal_empty_stock() {
+        let mut game = AcesUp::new(());
+        game.stock = Pile::new();
+        assert!(!game.is_valid(&Play::Deal));
+    }
+
+    #[test]
+    fn valid_discard_lower() {
+        let mut game = AcesUp::new(());
+        game.tableau[0].push(Face::Up(card!(H 5)));
+        game.tableau[2].push(Face::Up(card!(H 9)));
+        assert!(game.is_valid(&Play::Discard(Tableau::One)));
+    }
+
+    #[test]
+    fn valid_discard_king_under_ace() {
+        let mut game = AcesUp::new(());
+        game.tableau[0].push(Face::Up(card!(H K)));
+        game.tableau[1].push(Face::Up(card!(H A)));
+        assert!(game.is_valid(&Play::Discard(Tableau::One)));
+    }
+
+    #[test]
+    fn invalid_discard_ace() {
+        let mut game = AcesUp::new(());
+        game.tableau[0].push(Face::Up(card!(H A)));
+        game.tableau[1].push(Face::Up(card!(H K)));
+        assert!(!game.is_valid(&Play::Discard(Tableau::One)));
+    }
+
+    #[test]
+    fn invalid_discard_other_suit() {
+        let mut game = AcesUp::new(());
+        game.tableau[0].push(Face::Up(card!(H 5)));
+        game.tableau[1].push(Face::Up(card!(D 9)));
+        assert!(!game.is_valid(&Play::Discard(Tableau::One)));
+    }
+
+    #[test]
+    fn invalid_discard_covered() {
+        let mut game = AcesUp::new(());
+        game.tableau[0].push(Face::Up(card!(H 5)));
+        game.tableau[1].push(Face::Up(card!(H 9)));
+        game.tableau[1].push(Face::Up(card!(C 2)));
+        assert!(!game.is_valid(&Play::Discard(Tableau::One)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau_empty() {
+        let mut game = AcesUp::new(());
+        game.tableau[0].push(Face::Up(card!(H 5)));
+        assert!(game.is_valid(&Play::TableauTableau(Tableau::One, Tableau::Four)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_non_empty() {
+        let mut game = AcesUp::new(());
+        game.tableau[0].push(Face::Up(card!(H 5)));
+        game.tableau[3].push(Face::Up(card!(H 6)));
+        assert!(!game.is_valid(&Play::TableauTableau(Tableau::One, Tableau::Four)));
+    }
+}
+
+mod play {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::aces_up::{AcesUp, Play, Tableau};
+
+    #[test]
+    fn deal() {
+        let mut game = AcesUp::new(());
+        game.deal();
+        assert_eq!(48, game.stock.count());
+        assert!(game.tableau.iter().all(|pile| pile.count() == 1 && pile.top().unwrap().is_up()));
+    }
+
+    #[test]
+    fn discard() {
+        let mut game = AcesUp::new(());
+        game.tableau[0].push(Face::Up(card!(H 5)));
+        game.play(&Play::Discard(Tableau::One));
+        assert_eq!(Some(Face::Up(card!(H 5))), game.discard.top());
+        assert!(game.tableau[0].is_empty());
+    }
+
+    #[test]
+    fn won() {
+        let mut game = AcesUp::new(());
+        game.stock = Pile::new();
+        game.discard.vec = vec![Face::Up(card!(H 5)); 48];
+        game.tableau[0].push(Face::Up(card!(H A)));
+        game.tableau[0].push(Face::Up(card!(C A)));
+        game.tableau[2].push(Face::Up(card!(D A)));
+        game.tableau[3].push(Face::Up(card!(S A)));
+        assert!(game.is_won());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::aces_up::{AcesUp, Play, Tableau};
+
+    macro_rules! undo_test {
+        ($name:ident, $play:expr) => {
+            #[test]
+            fn $name() {
+                let mut before = AcesUp::new(());
+                before.deal();
+                before.play(&Play::Discard(Tableau::Four));
+                let mut after = before.clone();
+                after.play(&$play);
+                after.undo(&$play);
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    undo_test!(deal, Play::Deal);
+    undo_test!(discard, Play::Discard(Tableau::One));
+    undo_test!(tableau_tableau, Play::TableauTableau(Tableau::One, Tableau::Four));
+}
@@ -0,0 +1,40 @@
+use card::{Face, Set};
+use game::Game;
+use game::open::{self, OpenTableau};
+use super::{BakersDozen, Play};
+
+impl Game for BakersDozen {
+    type Rules = ();
+    type Play = Play;
+
+    fn new(_: ()) -> Self {
+        BakersDozen {
+            stock: Set::new().map(Face::Up).collect(),
+            open: OpenTableau::new(13, false),
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        for pile in &mut self.open.tableau {
+            self.stock.move_to(pile, 4);
+            open::kings_to_bottom(pile);
+        }
+    }
+
+    fn is_won(&self) -> bool {
+        self.open.is_won()
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        self.open.is_valid(play)
+    }
+
+    fn play(&mut self, play: &Play) {
+        self.open.play(play);
+    }
+
+    fn undo(&mut self, play: &Play) {
+        self.open.undo(play);
+    }
+}
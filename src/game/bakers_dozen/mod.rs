@@ -0,0 +1,18 @@
+//! Baker's Dozen solitaire.
+
+use card::Pile;
+use game::open::OpenTableau;
+
+pub use game::open::Play;
+
+/// Baker's Dozen solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BakersDozen {
+    stock: Pile,
+    open: OpenTableau,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,132 @@
+mod is_valid {
+    use card::Face;
+    use game::Game;
+    use game::bakers_dozen::{BakersDozen, Play};
+
+    #[test]
+    fn valid_tableau_foundation_ace() {
+        let mut game = BakersDozen::new(());
+        game.open.tableau[0].push(Face::Up(card!(S A)));
+        assert!(game.is_valid(&Play::TableauFoundation(0, 0)));
+    }
+
+    #[test]
+    fn valid_tableau_foundation_suit_succ() {
+        let mut game = BakersDozen::new(());
+        game.open.foundations[0].push(Face::Up(card!(S A)));
+        game.open.tableau[0].push(Face::Up(card!(S 2)));
+        assert!(game.is_valid(&Play::TableauFoundation(0, 0)));
+    }
+
+    #[test]
+    fn invalid_tableau_foundation_non_suit() {
+        let mut game = BakersDozen::new(());
+        game.open.foundations[0].push(Face::Up(card!(S A)));
+        game.open.tableau[0].push(Face::Up(card!(C 2)));
+        assert!(!game.is_valid(&Play::TableauFoundation(0, 0)));
+    }
+
+    #[test]
+    fn invalid_tableau_foundation_out_of_range() {
+        let mut game = BakersDozen::new(());
+        game.open.tableau[0].push(Face::Up(card!(S A)));
+        assert!(!game.is_valid(&Play::TableauFoundation(0, 4)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau_any_suit() {
+        let mut game = BakersDozen::new(());
+        game.open.tableau[0].push(Face::Up(card!(H 8)));
+        game.open.tableau[1].push(Face::Up(card!(D 9)));
+        assert!(game.is_valid(&Play::TableauTableau(0, 1)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_non_pred() {
+        let mut game = BakersDozen::new(());
+        game.open.tableau[0].push(Face::Up(card!(H 8)));
+        game.open.tableau[1].push(Face::Up(card!(D 10)));
+        assert!(!game.is_valid(&Play::TableauTableau(0, 1)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_empty() {
+        let mut game = BakersDozen::new(());
+        game.open.tableau[0].push(Face::Up(card!(H K)));
+        assert!(!game.is_valid(&Play::TableauTableau(0, 1)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_same() {
+        let mut game = BakersDozen::new(());
+        game.open.tableau[0].push(Face::Up(card!(H 9)));
+        game.open.tableau[0].push(Face::Up(card!(H 8)));
+        assert!(!game.is_valid(&Play::TableauTableau(0, 0)));
+    }
+}
+
+mod play {
+    use card::{Rank, Face};
+    use game::Game;
+    use game::bakers_dozen::{BakersDozen, Play};
+
+    #[test]
+    fn deal() {
+        let mut game = BakersDozen::new(());
+        game.deal();
+        assert!(game.stock.is_empty());
+        assert!(game.open.tableau.iter().all(|pile| pile.count() == 4));
+        assert!(game.open.tableau.iter().all(|pile| pile.into_iter().all(Face::is_up)));
+    }
+
+    #[test]
+    fn deal_kings_bottom() {
+        let mut game = BakersDozen::new(());
+        game.deal();
+        for pile in &game.open.tableau {
+            let kings = pile.into_iter().take_while(|face| face.card().rank == Rank::King).count();
+            assert!(pile.into_iter().skip(kings).all(|face| face.card().rank != Rank::King));
+        }
+    }
+
+    #[test]
+    fn tableau_tableau() {
+        let mut game = BakersDozen::new(());
+        game.deal();
+        let card = game.open.tableau[1].top();
+        game.play(&Play::TableauTableau(1, 0));
+        assert_eq!(card, game.open.tableau[0].top());
+        assert_eq!(5, game.open.tableau[0].count());
+    }
+
+    #[test]
+    fn won() {
+        let mut game = BakersDozen::new(());
+        for foundation in &mut game.open.foundations {
+            game.stock.move_to(foundation, 13);
+        }
+        assert!(game.is_won());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::bakers_dozen::{BakersDozen, Play};
+
+    macro_rules! undo_test {
+        ($name:ident, $play:expr) => {
+            #[test]
+            fn $name() {
+                let mut before = BakersDozen::new(());
+                before.deal();
+                let mut after = before.clone();
+                after.play(&$play);
+                after.undo(&$play);
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    undo_test!(tableau_foundation, Play::TableauFoundation(0, 0));
+    undo_test!(tableau_tableau, Play::TableauTableau(12, 0));
+}
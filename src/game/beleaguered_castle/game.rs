@@ -0,0 +1,45 @@
+use card::{Rank, Face, Set};
+use game::Game;
+use game::open::OpenTableau;
+use super::{BeleagueredCastle, Play};
+
+impl Game for BeleagueredCastle {
+    type Rules = ();
+    type Play = Play;
+
+    fn new(_: ()) -> Self {
+        BeleagueredCastle {
+            stock: Set::new().map(Face::Up).collect(),
+            open: OpenTableau::new(8, true),
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        let (aces, rest): (Vec<Face>, Vec<Face>) = self.stock.vec.drain(..)
+            .partition(|face| face.card().rank == Rank::Ace);
+        self.stock.vec = rest;
+        for (foundation, ace) in self.open.foundations.iter_mut().zip(aces) {
+            foundation.push(ace);
+        }
+        for pile in &mut self.open.tableau {
+            self.stock.move_to(pile, 6);
+        }
+    }
+
+    fn is_won(&self) -> bool {
+        self.open.is_won()
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        self.open.is_valid(play)
+    }
+
+    fn play(&mut self, play: &Play) {
+        self.open.play(play);
+    }
+
+    fn undo(&mut self, play: &Play) {
+        self.open.undo(play);
+    }
+}
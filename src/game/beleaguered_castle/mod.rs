@@ -0,0 +1,18 @@
+//! Beleaguered Castle solitaire.
+
+use card::Pile;
+use game::open::OpenTableau;
+
+pub use game::open::Play;
+
+/// Beleaguered Castle solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BeleagueredCastle {
+    stock: Pile,
+    open: OpenTableau,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,84 @@
+mod is_valid {
+    use card::Face;
+    use game::Game;
+    use game::beleaguered_castle::{BeleagueredCastle, Play};
+
+    #[test]
+    fn valid_tableau_foundation_suit_succ() {
+        let mut game = BeleagueredCastle::new(());
+        game.open.foundations[0].push(Face::Up(card!(S A)));
+        game.open.tableau[0].push(Face::Up(card!(S 2)));
+        assert!(game.is_valid(&Play::TableauFoundation(0, 0)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau_any_suit() {
+        let mut game = BeleagueredCastle::new(());
+        game.open.tableau[0].push(Face::Up(card!(H 8)));
+        game.open.tableau[1].push(Face::Up(card!(S 9)));
+        assert!(game.is_valid(&Play::TableauTableau(0, 1)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau_empty() {
+        let mut game = BeleagueredCastle::new(());
+        game.open.tableau[0].push(Face::Up(card!(H 8)));
+        assert!(game.is_valid(&Play::TableauTableau(0, 1)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_out_of_range() {
+        let mut game = BeleagueredCastle::new(());
+        game.open.tableau[0].push(Face::Up(card!(H 8)));
+        assert!(!game.is_valid(&Play::TableauTableau(0, 8)));
+    }
+}
+
+mod play {
+    use card::{Rank, Face};
+    use game::Game;
+    use game::beleaguered_castle::{BeleagueredCastle, Play};
+
+    #[test]
+    fn deal() {
+        let mut game = BeleagueredCastle::new(());
+        game.deal();
+        assert!(game.stock.is_empty());
+        assert!(game.open.foundations.iter().all(|pile| {
+            pile.count() == 1 && pile.top().unwrap().card().rank == Rank::Ace
+        }));
+        assert!(game.open.tableau.iter().all(|pile| pile.count() == 6));
+        assert!(game.open.tableau.iter().all(|pile| pile.into_iter().all(Face::is_up)));
+    }
+
+    #[test]
+    fn tableau_foundation() {
+        let mut game = BeleagueredCastle::new(());
+        game.deal();
+        let card = game.open.tableau[0].top();
+        game.play(&Play::TableauFoundation(0, 0));
+        assert_eq!(card, game.open.foundations[0].top());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::beleaguered_castle::{BeleagueredCastle, Play};
+
+    macro_rules! undo_test {
+        ($name:ident, $play:expr) => {
+            #[test]
+            fn $name() {
+                let mut before = BeleagueredCastle::new(());
+                before.deal();
+                let mut after = before.clone();
+                after.play(&$play);
+                after.undo(&$play);
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    undo_test!(tableau_foundation, Play::TableauFoundation(0, 0));
+    undo_test!(tableau_tableau, Play::TableauTableau(7, 0));
+}
@@ -0,0 +1,163 @@
+use std::mem;
+
+use rand::{self, XorShiftRng, SeedableRng};
+
+use card::{Rank, Card, Face, Set, Pile};
+use game::Game;
+use super::{BelleLucie, Rules, Play, Foundation};
+
+impl Game for BelleLucie {
+    type Rules = Rules;
+    type Play = Play;
+
+    fn new(rules: Rules) -> Self {
+        BelleLucie::with_seed(rules, rand::random())
+    }
+
+    fn deal(&mut self) {
+        let mut stock = mem::replace(&mut self.stock, Pile::new());
+        stock.shuffle_with(&mut self.rng());
+        self.deal_fans(stock);
+    }
+
+    fn is_won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.count() == 13)
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::Redeal => {
+                self.redeals.len() < self.rules.redeals as usize
+            },
+
+            Play::Merci(fan, index) => {
+                !self.merci && self.tableau.get(fan as usize).map_or(false, |pile| {
+                    (index as usize) + 1 < pile.count()
+                })
+            },
+
+            Play::TableauFoundation(fan, foundation) => {
+                self.tableau.get(fan as usize).and_then(Pile::top).map_or(false, |face| {
+                    self.is_valid_foundation(foundation, face.card())
+                })
+            },
+
+            Play::TableauTableau(src, dest) => {
+                src != dest && self.tableau.get(src as usize).and_then(Pile::top).map_or(false, |face| {
+                    self.is_valid_tableau(dest, face.card())
+                })
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::Redeal => {
+                let mut stock = self.tableau.iter().flatten().cloned().collect::<Pile>();
+                self.redeals.push(self.tableau.clone());
+                stock.shuffle_with(&mut self.rng());
+                self.deal_fans(stock);
+            },
+
+            Play::Merci(fan, index) => {
+                let pile = &mut self.tableau[fan as usize];
+                let face = pile.vec.remove(index as usize);
+                pile.push(face);
+                self.merci = true;
+            },
+
+            Play::TableauFoundation(fan, foundation) => {
+                self.tableau[fan as usize].move_to(&mut self.foundations[foundation as usize], 1);
+            },
+
+            Play::TableauTableau(src, dest) => {
+                if let Some(face) = self.tableau[src as usize].pop() {
+                    self.tableau[dest as usize].push(face);
+                }
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::Redeal => {
+                if let Some(tableau) = self.redeals.pop() {
+                    self.tableau = tableau;
+                }
+            },
+
+            Play::Merci(fan, index) => {
+                let pile = &mut self.tableau[fan as usize];
+                if let Some(face) = pile.pop() {
+                    pile.vec.insert(index as usize, face);
+                }
+                self.merci = false;
+            },
+
+            Play::TableauFoundation(fan, foundation) => {
+                self.foundations[foundation as usize].move_to(&mut self.tableau[fan as usize], 1);
+            },
+
+            Play::TableauTableau(src, dest) => {
+                self.play(&Play::TableauTableau(dest, src));
+            },
+        }
+    }
+}
+
+impl BelleLucie {
+    /// Creates a game whose deal and redeals are determined by a seed.
+    pub fn with_seed(rules: Rules, seed: u32) -> Self {
+        BelleLucie {
+            rules: rules,
+            seed: seed,
+            stock: Set::new().map(Face::Up).collect(),
+            foundations: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
+            tableau: Vec::new(),
+            redeals: Vec::new(),
+            merci: false,
+        }
+    }
+
+    /// Returns the seed.
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    /// Returns the number of redeals remaining.
+    pub fn redeals(&self) -> u8 {
+        self.rules.redeals.saturating_sub(self.redeals.len() as u8)
+    }
+
+    /// Returns true if the merci has not been played yet.
+    pub fn has_merci(&self) -> bool {
+        !self.merci
+    }
+
+    /// Returns a random number generator for the deal or the current redeal.
+    fn rng(&self) -> XorShiftRng {
+        XorShiftRng::from_seed([self.seed, self.redeals.len() as u32, 0x9e37_79b9, 0x7f4a_7c15])
+    }
+
+    /// Deals a pile into fans of three, with the last fan holding any remainder.
+    fn deal_fans(&mut self, stock: Pile) {
+        self.tableau = stock.vec.chunks(3).map(|chunk| chunk.iter().cloned().collect()).collect();
+    }
+
+    fn is_valid_foundation(&self, foundation: Foundation, card: Card) -> bool {
+        let foundation = &self.foundations[foundation as usize];
+        if foundation.is_empty() {
+            card.rank == Rank::Ace
+        } else {
+            let top = foundation.top().unwrap().card();
+            card.suit == top.suit && card.rank.pred() == Some(top.rank)
+        }
+    }
+
+    fn is_valid_tableau(&self, fan: u8, card: Card) -> bool {
+        self.tableau.get(fan as usize).and_then(Pile::top).map_or(false, |face| {
+            let top = face.card();
+            card.suit == top.suit && card.rank.succ() == Some(top.rank)
+        })
+    }
+}
@@ -0,0 +1,61 @@
+//! La Belle Lucie solitaire.
+
+use card::Pile;
+
+/// La Belle Lucie solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BelleLucie {
+    rules: Rules,
+    seed: u32,
+    stock: Pile,
+    foundations: [Pile; 4],
+    tableau: Vec<Pile>,
+    redeals: Vec<Vec<Pile>>,
+    merci: bool,
+}
+
+/// La Belle Lucie variation rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Number of times the tableau may be gathered, shuffled and dealt again.
+    pub redeals: u8,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules { redeals: 2 }
+    }
+}
+
+/// La Belle Lucie play.
+///
+/// Fans are numbered from zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Gather the tableau, shuffle it and deal it again in fans of three.
+    Redeal,
+
+    /// Move a buried card, by index from the bottom, to the top of its fan, once per game.
+    Merci(u8, u8),
+
+    /// Move top card of tableau to foundation.
+    TableauFoundation(u8, Foundation),
+
+    /// Move top card of tableau to tableau.
+    TableauTableau(u8, u8),
+}
+
+/// Foundations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Foundation {
+    One,
+    Two,
+    Three,
+    Four,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,176 @@
+mod is_valid {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::belle_lucie::{BelleLucie, Rules, Play, Foundation};
+
+    fn game() -> BelleLucie {
+        let mut game = BelleLucie::with_seed(Rules::default(), 1);
+        game.tableau = vec![Pile::new(); 18];
+        game
+    }
+
+    #[test]
+    fn valid_tableau_foundation_ace() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(S A)));
+        assert!(game.is_valid(&Play::TableauFoundation(0, Foundation::One)));
+    }
+
+    #[test]
+    fn invalid_tableau_foundation_non_ace() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(S 2)));
+        assert!(!game.is_valid(&Play::TableauFoundation(0, Foundation::One)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau_suit_pred() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(S 2)));
+        game.tableau[1].push(Face::Up(card!(S 3)));
+        assert!(game.is_valid(&Play::TableauTableau(0, 1)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_non_suit() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(S 2)));
+        game.tableau[1].push(Face::Up(card!(C 3)));
+        assert!(!game.is_valid(&Play::TableauTableau(0, 1)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_empty() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(S K)));
+        assert!(!game.is_valid(&Play::TableauTableau(0, 1)));
+    }
+
+    #[test]
+    fn valid_merci() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(S 2)));
+        game.tableau[0].push(Face::Up(card!(C 3)));
+        assert!(game.is_valid(&Play::Merci(0, 0)));
+    }
+
+    #[test]
+    fn invalid_merci_top() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(S 2)));
+        game.tableau[0].push(Face::Up(card!(C 3)));
+        assert!(!game.is_valid(&Play::Merci(0, 1)));
+    }
+
+    #[test]
+    fn invalid_merci_used() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(S 2)));
+        game.tableau[0].push(Face::Up(card!(C 3)));
+        game.tableau[0].push(Face::Up(card!(D 4)));
+        game.play(&Play::Merci(0, 0));
+        assert!(!game.is_valid(&Play::Merci(0, 0)));
+    }
+
+    #[test]
+    fn invalid_redeal_exhausted() {
+        let mut game = game();
+        game.deal();
+        game.play(&Play::Redeal);
+        assert!(game.is_valid(&Play::Redeal));
+        game.play(&Play::Redeal);
+        assert!(!game.is_valid(&Play::Redeal));
+    }
+}
+
+mod play {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::belle_lucie::{BelleLucie, Rules, Play, Foundation};
+
+    #[test]
+    fn deal() {
+        let mut game = BelleLucie::new(Rules::default());
+        game.deal();
+        assert!(game.stock.is_empty());
+        assert_eq!(18, game.tableau.len());
+        assert!(game.tableau[..17].iter().all(|pile| pile.count() == 3));
+        assert_eq!(1, game.tableau[17].count());
+    }
+
+    #[test]
+    fn deal_seeded() {
+        let mut a = BelleLucie::with_seed(Rules::default(), 42);
+        let mut b = BelleLucie::with_seed(Rules::default(), 42);
+        let mut c = BelleLucie::with_seed(Rules::default(), 43);
+        a.deal();
+        b.deal();
+        c.deal();
+        assert_eq!(a, b);
+        assert!(a.tableau != c.tableau);
+    }
+
+    #[test]
+    fn redeal_seeded() {
+        let mut a = BelleLucie::with_seed(Rules::default(), 42);
+        let mut b = BelleLucie::with_seed(Rules::default(), 42);
+        a.deal();
+        b.deal();
+        a.play(&Play::Redeal);
+        b.play(&Play::Redeal);
+        assert_eq!(a, b);
+        a.play(&Play::Redeal);
+        b.play(&Play::Redeal);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn redeal_gathers_tableau() {
+        let mut game = BelleLucie::with_seed(Rules::default(), 42);
+        game.deal();
+        game.play(&Play::TableauFoundation(0, Foundation::One));
+        game.play(&Play::TableauFoundation(1, Foundation::Two));
+        game.play(&Play::Redeal);
+        assert_eq!(17, game.tableau.len());
+        assert!(game.tableau[..16].iter().all(|pile| pile.count() == 3));
+        assert_eq!(2, game.tableau[16].count());
+        assert_eq!(1, game.redeals());
+    }
+
+    #[test]
+    fn merci() {
+        let mut game = BelleLucie::with_seed(Rules::default(), 1);
+        game.tableau = vec![Pile::new()];
+        game.tableau[0].push(Face::Up(card!(S 2)));
+        game.tableau[0].push(Face::Up(card!(C 3)));
+        game.tableau[0].push(Face::Up(card!(D 4)));
+        game.play(&Play::Merci(0, 0));
+        assert_eq!(Some(Face::Up(card!(S 2))), game.tableau[0].top());
+        assert_eq!(Some(Face::Up(card!(C 3))), game.tableau[0].get(0));
+        assert!(!game.has_merci());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::belle_lucie::{BelleLucie, Rules, Play, Foundation};
+
+    macro_rules! undo_test {
+        ($name:ident, $play:expr) => {
+            #[test]
+            fn $name() {
+                let mut before = BelleLucie::new(Rules::default());
+                before.deal();
+                let mut after = before.clone();
+                after.play(&$play);
+                after.undo(&$play);
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    undo_test!(redeal, Play::Redeal);
+    undo_test!(merci, Play::Merci(3, 1));
+    undo_test!(tableau_foundation, Play::TableauFoundation(0, Foundation::One));
+    undo_test!(tableau_tableau, Play::TableauTableau(2, 5));
+}
@@ -0,0 +1,122 @@
+use card::{Face, Set, Pile};
+use game::Game;
+use game::open::{self, OpenTableau};
+use super::{Bristol, Play};
+
+impl Game for Bristol {
+    type Rules = ();
+    type Play = Play;
+
+    fn new(_: ()) -> Self {
+        Bristol {
+            stock: Set::new().map(Face::Down).collect(),
+            deals: Vec::new(),
+            reserve: [Pile::new(), Pile::new(), Pile::new()],
+            open: OpenTableau::new(8, false),
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        for pile in &mut self.open.tableau {
+            self.stock.deal_to(pile, 3, true);
+            open::kings_to_bottom(pile);
+        }
+        for pile in &mut self.reserve {
+            self.stock.deal_to(pile, 1, true);
+        }
+    }
+
+    fn is_won(&self) -> bool {
+        self.open.is_won()
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::Deal => {
+                !self.stock.is_empty()
+            },
+
+            Play::ReserveFoundation(reserve, foundation) => {
+                self.reserve[reserve as usize].top().map_or(false, |face| {
+                    self.open.is_valid_foundation(foundation, face.card())
+                })
+            },
+
+            Play::ReserveTableau(reserve, tableau) => {
+                self.reserve[reserve as usize].top().map_or(false, |face| {
+                    self.open.is_valid_tableau(tableau, face.card())
+                })
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.open.is_valid(&open::Play::TableauFoundation(tableau, foundation))
+            },
+
+            Play::TableauTableau(src, dest) => {
+                self.open.is_valid(&open::Play::TableauTableau(src, dest))
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::Deal => {
+                let count = self.stock.count().min(self.reserve.len());
+                for pile in &mut self.reserve[..count] {
+                    self.stock.deal_to(pile, 1, true);
+                }
+                self.deals.push(count);
+            },
+
+            Play::ReserveFoundation(reserve, foundation) => {
+                self.reserve[reserve as usize].move_to(
+                    &mut self.open.foundations[foundation as usize],
+                    1,
+                );
+            },
+
+            Play::ReserveTableau(reserve, tableau) => {
+                self.reserve[reserve as usize].move_to(&mut self.open.tableau[tableau as usize], 1);
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.open.play(&open::Play::TableauFoundation(tableau, foundation));
+            },
+
+            Play::TableauTableau(src, dest) => {
+                self.open.play(&open::Play::TableauTableau(src, dest));
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::Deal => {
+                let count = self.deals.pop().unwrap_or(0);
+                for pile in self.reserve[..count].iter_mut().rev() {
+                    pile.deal_to(&mut self.stock, 1, true);
+                }
+            },
+
+            Play::ReserveFoundation(reserve, foundation) => {
+                self.open.foundations[foundation as usize].move_to(
+                    &mut self.reserve[reserve as usize],
+                    1,
+                );
+            },
+
+            Play::ReserveTableau(reserve, tableau) => {
+                self.open.tableau[tableau as usize].move_to(&mut self.reserve[reserve as usize], 1);
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.open.undo(&open::Play::TableauFoundation(tableau, foundation));
+            },
+
+            Play::TableauTableau(src, dest) => {
+                self.open.undo(&open::Play::TableauTableau(src, dest));
+            },
+        }
+    }
+}
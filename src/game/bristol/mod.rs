@@ -0,0 +1,48 @@
+//! Bristol solitaire.
+
+use card::Pile;
+use game::open::OpenTableau;
+
+/// Bristol solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bristol {
+    stock: Pile,
+    deals: Vec<usize>,
+    reserve: [Pile; 3],
+    open: OpenTableau,
+}
+
+/// Bristol play.
+///
+/// Foundations and fans are numbered from zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Deal a card from stock onto each reserve pile.
+    Deal,
+
+    /// Move top card of reserve to foundation.
+    ReserveFoundation(Reserve, u8),
+
+    /// Move top card of reserve to tableau.
+    ReserveTableau(Reserve, u8),
+
+    /// Move top card of tableau to foundation.
+    TableauFoundation(u8, u8),
+
+    /// Move top card of tableau to tableau.
+    TableauTableau(u8, u8),
+}
+
+/// Reserve piles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Reserve {
+    One,
+    Two,
+    Three,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
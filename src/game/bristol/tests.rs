@@ -0,0 +1,142 @@
+mod is_valid {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::bristol::{Bristol, Play, Reserve};
+
+    #[test]
+    fn valid_deal() {
+        let game = Bristol::new(());
+        assert!(game.is_valid(&Play::Deal));
+    }
+
+    #[test]
+    fn invalid_deal_empty_stock() {
+        let mut game = Bristol::new(());
+        game.stock = Pile::new();
+        assert!(!game.is_valid(&Play::Deal));
+    }
+
+    #[test]
+    fn valid_reserve_foundation_ace() {
+        let mut game = Bristol::new(());
+        game.reserve[1].push(Face::Up(card!(C A)));
+        assert!(game.is_valid(&Play::ReserveFoundation(Reserve::Two, 0)));
+    }
+
+    #[test]
+    fn invalid_reserve_foundation_non_ace() {
+        let mut game = Bristol::new(());
+        game.reserve[1].push(Face::Up(card!(C 2)));
+        assert!(!game.is_valid(&Play::ReserveFoundation(Reserve::Two, 0)));
+    }
+
+    #[test]
+    fn valid_reserve_tableau_any_suit() {
+        let mut game = Bristol::new(());
+        game.reserve[0].push(Face::Up(card!(C 7)));
+        game.open.tableau[3].push(Face::Up(card!(C 8)));
+        assert!(game.is_valid(&Play::ReserveTableau(Reserve::One, 3)));
+    }
+
+    #[test]
+    fn invalid_reserve_tableau_empty() {
+        let mut game = Bristol::new(());
+        game.reserve[0].push(Face::Up(card!(C K)));
+        assert!(!game.is_valid(&Play::ReserveTableau(Reserve::One, 3)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau() {
+        let mut game = Bristol::new(());
+        game.open.tableau[0].push(Face::Up(card!(H 7)));
+        game.open.tableau[3].push(Face::Up(card!(C 8)));
+        assert!(game.is_valid(&Play::TableauTableau(0, 3)));
+    }
+}
+
+mod play {
+    use card::{Rank, Face};
+    use game::Game;
+    use game::bristol::{Bristol, Play};
+
+    #[test]
+    fn deal() {
+        let mut game = Bristol::new(());
+        game.deal();
+        assert!(game.open.tableau.iter().all(|pile| pile.count() == 3));
+        assert!(game.open.tableau.iter().all(|pile| pile.into_iter().all(Face::is_up)));
+        assert!(game.reserve.iter().all(|pile| pile.count() == 1));
+        assert_eq!(25, game.stock.count());
+    }
+
+    #[test]
+    fn deal_kings_bottom() {
+        let mut game = Bristol::new(());
+        game.deal();
+        for pile in &game.open.tableau {
+            let kings = pile.into_iter().take_while(|face| face.card().rank == Rank::King).count();
+            assert!(pile.into_iter().skip(kings).all(|face| face.card().rank != Rank::King));
+        }
+    }
+
+    #[test]
+    fn deal_reserve() {
+        let mut game = Bristol::new(());
+        game.deal();
+        let (a, b, c) = (game.stock.get_back(1), game.stock.get_back(2), game.stock.get_back(3));
+        game.play(&Play::Deal);
+        assert_eq!(a.map(Face::flipped), game.reserve[0].top());
+        assert_eq!(b.map(Face::flipped), game.reserve[1].top());
+        assert_eq!(c.map(Face::flipped), game.reserve[2].top());
+    }
+
+    #[test]
+    fn deal_reserve_remainder() {
+        let mut game = Bristol::new(());
+        game.deal();
+        while !game.stock.is_empty() {
+            game.play(&Play::Deal);
+        }
+        assert_eq!(10, game.reserve[0].count());
+        assert_eq!(9, game.reserve[1].count());
+        assert_eq!(9, game.reserve[2].count());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::bristol::{Bristol, Play, Reserve};
+
+    macro_rules! undo_test {
+        ($name:ident, $play:expr) => {
+            #[test]
+            fn $name() {
+                let mut before = Bristol::new(());
+                before.deal();
+                let mut after = before.clone();
+                after.play(&$play);
+                after.undo(&$play);
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    #[test]
+    fn deal_remainder() {
+        let mut before = Bristol::new(());
+        before.deal();
+        for _ in 0..8 {
+            before.play(&Play::Deal);
+        }
+        let mut after = before.clone();
+        after.play(&Play::Deal);
+        after.undo(&Play::Deal);
+        assert_eq!(before, after);
+    }
+
+    undo_test!(deal, Play::Deal);
+    undo_test!(reserve_foundation, Play::ReserveFoundation(Reserve::One, 0));
+    undo_test!(reserve_tableau, Play::ReserveTableau(Reserve::Two, 5));
+    undo_test!(tableau_foundation, Play::TableauFoundation(1, 2));
+    undo_test!(tableau_tableau, Play::TableauTableau(7, 0));
+}
@@ -0,0 +1,35 @@
+//! Tableau building rules shared by games that build down by suit or by color.
+
+use card::{Card, Face, Pile};
+
+/// Tableau building rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Build {
+    /// Build down in the same suit.
+    Suit,
+
+    /// Build down in alternate colors.
+    AlternateColor,
+}
+
+impl Build {
+    /// Returns true if `card` may be built on `top`.
+    pub fn is_built(self, top: Card, card: Card) -> bool {
+        let follows = match self {
+            Build::Suit => card.suit == top.suit,
+            Build::AlternateColor => card.suit.color() != top.suit.color(),
+        };
+        follows && card.rank.succ() == Some(top.rank)
+    }
+
+    /// Returns true if the top `count` cards of a pile are face-up and built in sequence.
+    pub fn is_sequence(self, pile: &Pile, count: u8) -> bool {
+        let count = count as usize;
+        if count == 0 || count > pile.count() {
+            return false;
+        }
+        let cards = &pile.vec[pile.count() - count..];
+        cards.iter().all(Face::is_up)
+            && cards.windows(2).all(|pair| self.is_built(pair[0].card(), pair[1].card()))
+    }
+}
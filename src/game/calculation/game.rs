@@ -0,0 +1,101 @@
+use card::{Rank, Card, Face, Set, Pile};
+use game::Game;
+use super::{Calculation, Play, Foundation};
+
+impl Game for Calculation {
+    type Rules = ();
+    type Play = Play;
+
+    fn new(_: ()) -> Self {
+        Calculation {
+            stock: Set::new().map(Face::Down).collect(),
+            waste: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
+            foundations: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        let mut rank = Rank::Ace;
+        for foundation in &mut self.foundations {
+            let index = self.stock.vec.iter().position(|face| face.card().rank == rank).unwrap();
+            foundation.push(self.stock.vec.remove(index).flipped());
+            rank = rank.succ_wrapping();
+        }
+    }
+
+    fn is_won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.count() == 13)
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::StockFoundation(foundation) => {
+                self.stock.top().map_or(false, |face| {
+                    self.is_valid_foundation(foundation, face.card())
+                })
+            },
+
+            Play::StockWaste(_) => {
+                !self.stock.is_empty()
+            },
+
+            Play::WasteFoundation(waste, foundation) => {
+                self.waste[waste as usize].top().map_or(false, |face| {
+                    self.is_valid_foundation(foundation, face.card())
+                })
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::StockFoundation(foundation) => {
+                self.stock.deal_to(&mut self.foundations[foundation as usize], 1, true);
+            },
+
+            Play::StockWaste(waste) => {
+                self.stock.deal_to(&mut self.waste[waste as usize], 1, true);
+            },
+
+            Play::WasteFoundation(waste, foundation) => {
+                self.waste[waste as usize].move_to(&mut self.foundations[foundation as usize], 1);
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::StockFoundation(foundation) => {
+                self.foundations[foundation as usize].deal_to(&mut self.stock, 1, true);
+            },
+
+            Play::StockWaste(waste) => {
+                self.waste[waste as usize].deal_to(&mut self.stock, 1, true);
+            },
+
+            Play::WasteFoundation(waste, foundation) => {
+                self.foundations[foundation as usize].move_to(&mut self.waste[waste as usize], 1);
+            },
+        }
+    }
+}
+
+impl Calculation {
+    /// Returns the rank next needed on a foundation, if it is not complete.
+    pub fn next_rank(&self, foundation: Foundation) -> Option<Rank> {
+        let interval = foundation as u8 + 1;
+        let pile = &self.foundations[foundation as usize];
+        if pile.count() >= 13 {
+            return None;
+        }
+        match pile.top() {
+            None => Some(Rank::Ace.add_wrapping(interval - 1)),
+            Some(face) => Some(face.card().rank.add_wrapping(interval)),
+        }
+    }
+
+    fn is_valid_foundation(&self, foundation: Foundation, card: Card) -> bool {
+        self.next_rank(foundation) == Some(card.rank)
+    }
+}
@@ -0,0 +1,49 @@
+//! Calculation solitaire, also known as Broken Intervals.
+
+use card::Pile;
+
+/// Calculation solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Calculation {
+    stock: Pile,
+    waste: [Pile; 4],
+    foundations: [Pile; 4],
+}
+
+/// Calculation play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Move top card of stock to foundation.
+    StockFoundation(Foundation),
+
+    /// Move top card of stock to waste.
+    StockWaste(Waste),
+
+    /// Move top card of waste to foundation.
+    WasteFoundation(Waste, Foundation),
+}
+
+/// Foundations, starting at ace, two, three and four, and building up by the same interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Foundation {
+    One,
+    Two,
+    Three,
+    Four,
+}
+
+/// Waste piles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Waste {
+    One,
+    Two,
+    Three,
+    Four,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,135 @@
+mod is_valid {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::calculation::{Calculation, Play, Foundation, Waste};
+
+    fn game(top: Face) -> Calculation {
+        let mut game = Calculation::new(());
+        game.stock = Pile::new();
+        game.stock.push(top);
+        game
+    }
+
+    #[test]
+    fn valid_stock_foundation_base() {
+        let game = game(Face::Down(card!(H 3)));
+        assert!(game.is_valid(&Play::StockFoundation(Foundation::Three)));
+    }
+
+    #[test]
+    fn valid_stock_foundation_interval() {
+        let mut game = game(Face::Down(card!(H 6)));
+        game.foundations[1].push(Face::Up(card!(C 2)));
+        game.foundations[1].push(Face::Up(card!(S 4)));
+        assert!(game.is_valid(&Play::StockFoundation(Foundation::Two)));
+    }
+
+    #[test]
+    fn valid_stock_foundation_wrap() {
+        let mut game = game(Face::Down(card!(H 3)));
+        game.foundations[3].push(Face::Up(card!(C Q)));
+        assert!(game.is_valid(&Play::StockFoundation(Foundation::Four)));
+    }
+
+    #[test]
+    fn invalid_stock_foundation_interval() {
+        let mut game = game(Face::Down(card!(H 5)));
+        game.foundations[1].push(Face::Up(card!(C 2)));
+        game.foundations[1].push(Face::Up(card!(S 4)));
+        assert!(!game.is_valid(&Play::StockFoundation(Foundation::Two)));
+    }
+
+    #[test]
+    fn invalid_stock_foundation_complete() {
+        let mut game = game(Face::Down(card!(H A)));
+        game.foundations[0].vec = vec![Face::Up(card!(C K)); 13];
+        assert!(!game.is_valid(&Play::StockFoundation(Foundation::One)));
+    }
+
+    #[test]
+    fn valid_stock_waste() {
+        let game = game(Face::Down(card!(H 5)));
+        assert!(game.is_valid(&Play::StockWaste(Waste::Two)));
+    }
+
+    #[test]
+    fn invalid_stock_waste_empty() {
+        let mut game = Calculation::new(());
+        game.stock = Pile::new();
+        assert!(!game.is_valid(&Play::StockWaste(Waste::Two)));
+    }
+
+    #[test]
+    fn valid_waste_foundation() {
+        let mut game = Calculation::new(());
+        game.waste[2].push(Face::Up(card!(D 4)));
+        assert!(game.is_valid(&Play::WasteFoundation(Waste::Three, Foundation::Four)));
+    }
+
+    #[test]
+    fn invalid_waste_foundation_empty() {
+        let game = Calculation::new(());
+        assert!(!game.is_valid(&Play::WasteFoundation(Waste::Three, Foundation::Four)));
+    }
+}
+
+mod play {
+    use card::Rank;
+    use game::Game;
+    use game::calculation::{Calculation, Play, Foundation, Waste};
+
+    #[test]
+    fn deal() {
+        let mut game = Calculation::new(());
+        game.deal();
+        assert_eq!(48, game.stock.count());
+        let bases = game.foundations.iter()
+            .map(|pile| pile.top().map(|face| face.card().rank))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Some(Rank::Ace), Some(Rank::Two), Some(Rank::Three), Some(Rank::Four)], bases);
+        assert!(game.foundations.iter().all(|pile| pile.top().unwrap().is_up()));
+    }
+
+    #[test]
+    fn next_rank() {
+        let mut game = Calculation::new(());
+        game.deal();
+        assert_eq!(Some(Rank::Two), game.next_rank(Foundation::One));
+        assert_eq!(Some(Rank::Four), game.next_rank(Foundation::Two));
+        assert_eq!(Some(Rank::Six), game.next_rank(Foundation::Three));
+        assert_eq!(Some(Rank::Eight), game.next_rank(Foundation::Four));
+    }
+
+    #[test]
+    fn stock_waste() {
+        let mut game = Calculation::new(());
+        let top = game.stock.top().map(|face| face.card());
+        game.play(&Play::StockWaste(Waste::Four));
+        assert_eq!(top, game.waste[3].top().map(|face| face.card()));
+        assert!(game.waste[3].top().unwrap().is_up());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::calculation::{Calculation, Play, Foundation, Waste};
+
+    macro_rules! undo_test {
+        ($name:ident, $play:expr) => {
+            #[test]
+            fn $name() {
+                let mut before = Calculation::new(());
+                before.deal();
+                before.play(&Play::StockWaste(Waste::One));
+                let mut after = before.clone();
+                after.play(&$play);
+                after.undo(&$play);
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    undo_test!(stock_foundation, Play::StockFoundation(Foundation::One));
+    undo_test!(stock_waste, Play::StockWaste(Waste::Two));
+    undo_test!(waste_foundation, Play::WasteFoundation(Waste::One, Foundation::Three));
+}
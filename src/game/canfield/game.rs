@@ -0,0 +1,214 @@
+use std::mem;
+
+use card::{Rank, Card, Face, Set, Pile};
+use game::Game;
+use super::{Canfield, Rules, Play, Foundation, Tableau};
+
+impl Game for Canfield {
+    type Rules = Rules;
+    type Play = Play;
+
+    fn new(rules: Rules) -> Self {
+        Canfield {
+            rules: rules,
+            base: Rank::Ace,
+            stock: Set::new().map(Face::Down).collect(),
+            waste: Pile::new(),
+            draws: Vec::new(),
+            reserve: Pile::new(),
+            foundations: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
+            tableau: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        self.stock.deal_to(&mut self.reserve, 13, false);
+        self.reserve.flip_top();
+        self.stock.deal_to(&mut self.foundations[0], 1, true);
+        self.base = self.foundations[0].top().unwrap().card().rank;
+        for pile in &mut self.tableau {
+            self.stock.deal_to(pile, 1, true);
+        }
+    }
+
+    fn is_won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.count() == 13)
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::Draw => {
+                !self.stock.is_empty()
+            },
+
+            Play::Redeal => {
+                self.stock.is_empty() && !self.waste.is_empty()
+            },
+
+            Play::WasteTableau(tableau) => {
+                self.waste.top().map_or(false, |face| {
+                    if self.tableau[tableau as usize].is_empty() {
+                        self.reserve.is_empty()
+                    } else {
+                        self.is_valid_tableau(tableau, face.card())
+                    }
+                })
+            },
+
+            Play::WasteFoundation(foundation) => {
+                self.waste.top().map_or(false, |face| {
+                    self.is_valid_foundation(foundation, face.card())
+                })
+            },
+
+            Play::ReserveTableau(tableau) => {
+                self.reserve.top().map_or(false, |face| {
+                    self.tableau[tableau as usize].is_empty()
+                        || self.is_valid_tableau(tableau, face.card())
+                })
+            },
+
+            Play::ReserveFoundation(foundation) => {
+                self.reserve.top().map_or(false, |face| {
+                    self.is_valid_foundation(foundation, face.card())
+                })
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.tableau[tableau as usize].top().map_or(false, |face| {
+                    self.is_valid_foundation(foundation, face.card())
+                })
+            },
+
+            Play::TableauTableau(src, count, dest) => {
+                let pile = &self.tableau[src as usize];
+                src != dest
+                    && (count == 1 || count as usize == pile.count())
+                    && pile.get_back(count as usize).map_or(false, |face| {
+                        !self.tableau[dest as usize].is_empty()
+                            && self.is_valid_tableau(dest, face.card())
+                    })
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::Draw => {
+                let count = self.stock.count().min(self.rules.draw as usize);
+                self.stock.deal_to(&mut self.waste, count, true);
+                self.draws.push(count);
+            },
+
+            Play::Redeal => {
+                mem::swap(&mut self.waste, &mut self.stock);
+                self.stock.flip();
+            },
+
+            Play::WasteTableau(tableau) => {
+                self.waste.move_to(&mut self.tableau[tableau as usize], 1);
+            },
+
+            Play::WasteFoundation(foundation) => {
+                self.waste.move_to(&mut self.foundations[foundation as usize], 1);
+            },
+
+            Play::ReserveTableau(tableau) => {
+                self.reserve.move_to(&mut self.tableau[tableau as usize], 1);
+                self.reserve.flip_top();
+            },
+
+            Play::ReserveFoundation(foundation) => {
+                self.reserve.move_to(&mut self.foundations[foundation as usize], 1);
+                self.reserve.flip_top();
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.tableau[tableau as usize].move_to(
+                    &mut self.foundations[foundation as usize],
+                    1,
+                );
+            },
+
+            Play::TableauTableau(src, count, dest) => {
+                if src < dest {
+                    let (left, right) = self.tableau.split_at_mut(dest as usize);
+                    left[src as usize].move_to(&mut right[0], count as usize);
+                } else {
+                    let (left, right) = self.tableau.split_at_mut(src as usize);
+                    right[0].move_to(&mut left[dest as usize], count as usize);
+                }
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::Draw => {
+                let count = self.draws.pop().unwrap_or(0);
+                self.waste.deal_to(&mut self.stock, count, true);
+            },
+
+            Play::Redeal => {
+                mem::swap(&mut self.stock, &mut self.waste);
+                self.waste.flip();
+            },
+
+            Play::WasteTableau(tableau) => {
+                self.tableau[tableau as usize].move_to(&mut self.waste, 1);
+            },
+
+            Play::WasteFoundation(foundation) => {
+                self.foundations[foundation as usize].move_to(&mut self.waste, 1);
+            },
+
+            Play::ReserveTableau(tableau) => {
+                self.reserve.flip_top();
+                self.tableau[tableau as usize].move_to(&mut self.reserve, 1);
+            },
+
+            Play::ReserveFoundation(foundation) => {
+                self.reserve.flip_top();
+                self.foundations[foundation as usize].move_to(&mut self.reserve, 1);
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.foundations[foundation as usize].move_to(
+                    &mut self.tableau[tableau as usize],
+                    1,
+                );
+            },
+
+            Play::TableauTableau(src, count, dest) => {
+                self.play(&Play::TableauTableau(dest, count, src));
+            },
+        }
+    }
+}
+
+impl Canfield {
+    /// Returns the base rank of the foundations, chosen by the first card dealt to them.
+    pub fn base(&self) -> Rank {
+        self.base
+    }
+
+    fn is_valid_foundation(&self, foundation: Foundation, card: Card) -> bool {
+        let foundation = &self.foundations[foundation as usize];
+        if foundation.is_empty() {
+            card.rank == self.base
+        } else {
+            let top = foundation.top().unwrap().card();
+            card.suit == top.suit
+                && top.rank.succ_wrapping() == card.rank
+        }
+    }
+
+    fn is_valid_tableau(&self, tableau: Tableau, card: Card) -> bool {
+        self.tableau[tableau as usize].top().map_or(false, |face| {
+            let top = face.card();
+            card.suit.color() != top.suit.color()
+                && card.rank.succ_wrapping() == top.rank
+        })
+    }
+}
@@ -0,0 +1,82 @@
+//! Canfield solitaire, also known as Demon.
+
+use card::{Rank, Pile};
+
+/// Canfield solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canfield {
+    rules: Rules,
+    base: Rank,
+    stock: Pile,
+    waste: Pile,
+    draws: Vec<usize>,
+    reserve: Pile,
+    foundations: [Pile; 4],
+    tableau: [Pile; 4],
+}
+
+/// Canfield variation rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Number of cards drawn from stock at a time.
+    pub draw: u8,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules { draw: 3 }
+    }
+}
+
+/// Canfield play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Draw from stock into waste.
+    Draw,
+
+    /// Move waste back to stock.
+    Redeal,
+
+    /// Move top card of waste to tableau.
+    WasteTableau(Tableau),
+
+    /// Move top card of waste to foundation.
+    WasteFoundation(Foundation),
+
+    /// Move top card of reserve to tableau.
+    ReserveTableau(Tableau),
+
+    /// Move top card of reserve to foundation.
+    ReserveFoundation(Foundation),
+
+    /// Move top card of tableau to foundation.
+    TableauFoundation(Tableau, Foundation),
+
+    /// Move the top card or the whole pile from tableau to tableau.
+    TableauTableau(Tableau, u8, Tableau),
+}
+
+/// Foundations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Foundation {
+    One,
+    Two,
+    Three,
+    Four,
+}
+
+/// Tableaux.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Tableau {
+    One,
+    Two,
+    Three,
+    Four,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
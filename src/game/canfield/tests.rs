@@ -0,0 +1,262 @@
+mod is_valid {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::canfield::{Canfield, Rules, Play, Foundation, Tableau};
+
+    #[test]
+    fn valid_draw_full_stock() {
+        let game = Canfield::new(Rules::default());
+        assert!(game.is_valid(&Play::Draw));
+    }
+
+    #[test]
+    fn invalid_draw_empty_stock() {
+        let mut game = Canfield::new(Rules::default());
+        game.stock = Pile::new();
+        assert!(!game.is_valid(&Play::Draw));
+    }
+
+    #[test]
+    fn valid_redeal_empty_stock() {
+        let mut game = Canfield::new(Rules::default());
+        game.stock.deal_to(&mut game.waste, 52, true);
+        assert!(game.is_valid(&Play::Redeal));
+    }
+
+    #[test]
+    fn invalid_redeal_non_empty_stock() {
+        let mut game = Canfield::new(Rules::default());
+        game.stock.deal_to(&mut game.waste, 1, true);
+        assert!(!game.is_valid(&Play::Redeal));
+    }
+
+    #[test]
+    fn valid_waste_foundation_base() {
+        let mut game = Canfield::new(Rules::default());
+        game.base = rank!(7);
+        game.waste.push(Face::Up(card!(S 7)));
+        assert!(game.is_valid(&Play::WasteFoundation(Foundation::One)));
+    }
+
+    #[test]
+    fn invalid_waste_foundation_non_base() {
+        let mut game = Canfield::new(Rules::default());
+        game.base = rank!(7);
+        game.waste.push(Face::Up(card!(S A)));
+        assert!(!game.is_valid(&Play::WasteFoundation(Foundation::One)));
+    }
+
+    #[test]
+    fn valid_waste_foundation_wrap() {
+        let mut game = Canfield::new(Rules::default());
+        game.base = rank!(7);
+        game.foundations[0].push(Face::Up(card!(S K)));
+        game.waste.push(Face::Up(card!(S A)));
+        assert!(game.is_valid(&Play::WasteFoundation(Foundation::One)));
+    }
+
+    #[test]
+    fn invalid_waste_foundation_non_suit() {
+        let mut game = Canfield::new(Rules::default());
+        game.base = rank!(7);
+        game.foundations[0].push(Face::Up(card!(S 7)));
+        game.waste.push(Face::Up(card!(C 8)));
+        assert!(!game.is_valid(&Play::WasteFoundation(Foundation::One)));
+    }
+
+    #[test]
+    fn valid_waste_tableau_pred_color() {
+        let mut game = Canfield::new(Rules::default());
+        game.tableau[0].push(Face::Up(card!(C 9)));
+        game.waste.push(Face::Up(card!(H 8)));
+        assert!(game.is_valid(&Play::WasteTableau(Tableau::One)));
+    }
+
+    #[test]
+    fn valid_waste_tableau_wrap() {
+        let mut game = Canfield::new(Rules::default());
+        game.tableau[0].push(Face::Up(card!(C A)));
+        game.waste.push(Face::Up(card!(H K)));
+        assert!(game.is_valid(&Play::WasteTableau(Tableau::One)));
+    }
+
+    #[test]
+    fn invalid_waste_tableau_non_color() {
+        let mut game = Canfield::new(Rules::default());
+        game.tableau[0].push(Face::Up(card!(C 9)));
+        game.waste.push(Face::Up(card!(S 8)));
+        assert!(!game.is_valid(&Play::WasteTableau(Tableau::One)));
+    }
+
+    #[test]
+    fn invalid_waste_tableau_empty_with_reserve() {
+        let mut game = Canfield::new(Rules::default());
+        game.reserve.push(Face::Up(card!(S 2)));
+        game.waste.push(Face::Up(card!(S 8)));
+        assert!(!game.is_valid(&Play::WasteTableau(Tableau::One)));
+    }
+
+    #[test]
+    fn valid_waste_tableau_empty_without_reserve() {
+        let mut game = Canfield::new(Rules::default());
+        game.waste.push(Face::Up(card!(S 8)));
+        assert!(game.is_valid(&Play::WasteTableau(Tableau::One)));
+    }
+
+    #[test]
+    fn valid_reserve_tableau_empty() {
+        let mut game = Canfield::new(Rules::default());
+        game.reserve.push(Face::Up(card!(S 2)));
+        assert!(game.is_valid(&Play::ReserveTableau(Tableau::One)));
+    }
+
+    #[test]
+    fn valid_reserve_foundation() {
+        let mut game = Canfield::new(Rules::default());
+        game.reserve.push(Face::Up(card!(S A)));
+        assert!(game.is_valid(&Play::ReserveFoundation(Foundation::Two)));
+    }
+
+    #[test]
+    fn valid_tableau_foundation() {
+        let mut game = Canfield::new(Rules::default());
+        game.foundations[0].push(Face::Up(card!(D A)));
+        game.tableau[0].push(Face::Up(card!(D 2)));
+        assert!(game.is_valid(&Play::TableauFoundation(Tableau::One, Foundation::One)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau() {
+        let mut game = Canfield::new(Rules::default());
+        game.tableau[0].push(Face::Up(card!(D 5)));
+        game.tableau[0].push(Face::Up(card!(S 4)));
+        game.tableau[1].push(Face::Up(card!(C 6)));
+        assert!(game.is_valid(&Play::TableauTableau(Tableau::One, 2, Tableau::Two)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau_top_card() {
+        let mut game = Canfield::new(Rules::default());
+        game.tableau[0].push(Face::Up(card!(D 5)));
+        game.tableau[0].push(Face::Up(card!(S 4)));
+        game.tableau[1].push(Face::Up(card!(H 5)));
+        assert!(game.is_valid(&Play::TableauTableau(Tableau::One, 1, Tableau::Two)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_part_of_pile() {
+        let mut game = Canfield::new(Rules::default());
+        game.tableau[0].push(Face::Up(card!(C 6)));
+        game.tableau[0].push(Face::Up(card!(D 5)));
+        game.tableau[0].push(Face::Up(card!(S 4)));
+        game.tableau[1].push(Face::Up(card!(C 6)));
+        assert!(!game.is_valid(&Play::TableauTableau(Tableau::One, 2, Tableau::Two)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_empty() {
+        let mut game = Canfield::new(Rules::default());
+        game.tableau[0].push(Face::Up(card!(D 5)));
+        assert!(!game.is_valid(&Play::TableauTableau(Tableau::One, 1, Tableau::Two)));
+    }
+}
+
+mod play {
+    use card::Face;
+    use game::Game;
+    use game::canfield::{Canfield, Rules, Play, Tableau};
+
+    #[test]
+    fn deal() {
+        let mut game = Canfield::new(Rules::default());
+        game.deal();
+        assert_eq!(13, game.reserve.count());
+        assert!(game.reserve.top().unwrap().is_up());
+        assert!(game.reserve.get_back(2).unwrap().is_down());
+        assert_eq!(Some(game.base), game.foundations[0].top().map(|face| face.card().rank));
+        assert!(game.tableau.iter().all(|pile| pile.count() == 1));
+        assert_eq!(34, game.stock.count());
+    }
+
+    #[test]
+    fn draw_three() {
+        let mut game = Canfield::new(Rules::default());
+        let (a, b, c) = (game.stock.get_back(1), game.stock.get_back(2), game.stock.get_back(3));
+        game.play(&Play::Draw);
+        assert_eq!(a.map(Face::flipped), game.waste.get_back(3));
+        assert_eq!(b.map(Face::flipped), game.waste.get_back(2));
+        assert_eq!(c.map(Face::flipped), game.waste.get_back(1));
+    }
+
+    #[test]
+    fn reserve_tableau_reveals() {
+        let mut game = Canfield::new(Rules::default());
+        game.deal();
+        let card = game.reserve.top();
+        game.play(&Play::ReserveTableau(Tableau::One));
+        assert_eq!(card, game.tableau[0].top());
+        assert!(game.reserve.top().unwrap().is_up());
+    }
+}
+
+mod undo {
+    use card::Face;
+    use game::Game;
+    use game::canfield::{Canfield, Rules, Play, Foundation, Tableau};
+
+    macro_rules! undo_test {
+        ($name:ident, $play:expr) => {
+            #[test]
+            fn $name() {
+                let mut before = Canfield::new(Rules::default());
+                before.deal();
+                before.play(&Play::Draw);
+                let mut after = before.clone();
+                after.play(&$play);
+                after.undo(&$play);
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    #[test]
+    fn draw_partial() {
+        let mut before = Canfield::new(Rules::default());
+        before.stock = before.stock.vec[..2].iter().cloned().collect();
+        let mut after = before.clone();
+        after.play(&Play::Draw);
+        after.undo(&Play::Draw);
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn redeal() {
+        let mut before = Canfield::new(Rules::default());
+        before.deal();
+        while !before.stock.is_empty() {
+            before.play(&Play::Draw);
+        }
+        let mut after = before.clone();
+        after.play(&Play::Redeal);
+        after.undo(&Play::Redeal);
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn reserve_foundation() {
+        let mut before = Canfield::new(Rules::default());
+        before.reserve.push(Face::Down(card!(H 2)));
+        before.reserve.push(Face::Up(card!(H A)));
+        let mut after = before.clone();
+        after.play(&Play::ReserveFoundation(Foundation::One));
+        after.undo(&Play::ReserveFoundation(Foundation::One));
+        assert_eq!(before, after);
+    }
+
+    undo_test!(draw, Play::Draw);
+    undo_test!(waste_tableau, Play::WasteTableau(Tableau::One));
+    undo_test!(waste_foundation, Play::WasteFoundation(Foundation::Two));
+    undo_test!(reserve_tableau, Play::ReserveTableau(Tableau::One));
+    undo_test!(tableau_foundation, Play::TableauFoundation(Tableau::One, Foundation::Two));
+    undo_test!(tableau_tableau, Play::TableauTableau(Tableau::One, 1, Tableau::Two));
+}
@@ -0,0 +1,100 @@
+use card::{Rank, Face, Set, Pile};
+use game::Game;
+use super::{Clock, Play};
+
+impl Game for Clock {
+    type Rules = ();
+    type Play = Play;
+
+    fn new(_: ()) -> Self {
+        Clock {
+            stock: Set::new().map(Face::Down).collect(),
+            piles: [
+                Pile::new(), Pile::new(), Pile::new(), Pile::new(), Pile::new(),
+                Pile::new(), Pile::new(), Pile::new(), Pile::new(), Pile::new(),
+                Pile::new(), Pile::new(), Pile::new(),
+            ],
+            current: Rank::King,
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        for _ in 0..4 {
+            for pile in &mut self.piles {
+                self.stock.deal_to(pile, 1, false);
+            }
+        }
+    }
+
+    fn is_won(&self) -> bool {
+        self.stock.is_empty()
+            && self.piles.iter().all(|pile| pile.into_iter().all(Face::is_up))
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::Turn(rank) => {
+                rank == self.current
+                    && self.pile(rank).top().as_ref().map_or(false, Face::is_down)
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::Turn(rank) => {
+                if let Some(face) = self.pile_mut(rank).pop() {
+                    let card = face.card();
+                    self.pile_mut(card.rank).vec.insert(0, Face::Up(card));
+                    self.current = card.rank;
+                }
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::Turn(rank) => {
+                let current = self.current;
+                if !self.pile(current).is_empty() {
+                    let card = self.pile_mut(current).vec.remove(0).card();
+                    self.pile_mut(rank).push(Face::Down(card));
+                    self.current = rank;
+                }
+            },
+        }
+    }
+}
+
+impl Clock {
+    /// Returns the play that is currently available, if any.
+    ///
+    /// The game is lost when no play is available before it is won, i.e. when the fourth king
+    /// is turned up too early.
+    pub fn next_play(&self) -> Option<Play> {
+        let play = Play::Turn(self.current);
+        if self.is_valid(&play) { Some(play) } else { None }
+    }
+
+    /// Returns true if the game is lost.
+    pub fn is_lost(&self) -> bool {
+        !self.is_won() && self.next_play().is_none()
+    }
+
+    /// Plays the game out, returning true if it is won.
+    pub fn autoplay(&mut self) -> bool {
+        while let Some(play) = self.next_play() {
+            self.play(&play);
+        }
+        self.is_won()
+    }
+
+    fn pile(&self, rank: Rank) -> &Pile {
+        &self.piles[rank as usize - 1]
+    }
+
+    fn pile_mut(&mut self, rank: Rank) -> &mut Pile {
+        &mut self.piles[rank as usize - 1]
+    }
+}
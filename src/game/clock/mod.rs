@@ -0,0 +1,24 @@
+//! Clock patience.
+
+use card::{Rank, Pile};
+
+/// Clock patience game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clock {
+    stock: Pile,
+    piles: [Pile; 13],
+    current: Rank,
+}
+
+/// Clock play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Turn the top card of the pile of a rank and place it face-up under the pile of its own
+    /// rank.
+    Turn(Rank),
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,122 @@
+mod is_valid {
+    use card::Face;
+    use game::Game;
+    use game::clock::{Clock, Play};
+
+    #[test]
+    fn valid_turn_king_first() {
+        let mut game = Clock::new(());
+        game.deal();
+        assert!(game.is_valid(&Play::Turn(rank!(K))));
+    }
+
+    #[test]
+    fn invalid_turn_non_current() {
+        let mut game = Clock::new(());
+        game.deal();
+        assert!(!game.is_valid(&Play::Turn(rank!(Q))));
+    }
+
+    #[test]
+    fn invalid_turn_face_up() {
+        let mut game = Clock::new(());
+        game.piles[12].push(Face::Up(card!(S K)));
+        assert!(!game.is_valid(&Play::Turn(rank!(K))));
+    }
+
+    #[test]
+    fn invalid_turn_empty() {
+        let game = Clock::new(());
+        assert!(!game.is_valid(&Play::Turn(rank!(K))));
+    }
+}
+
+mod play {
+    use card::Face;
+    use game::Game;
+    use game::clock::{Clock, Play};
+
+    #[test]
+    fn deal() {
+        let mut game = Clock::new(());
+        game.deal();
+        assert!(game.stock.is_empty());
+        assert!(game.piles.iter().all(|pile| pile.count() == 4));
+        assert!(game.piles.iter().all(|pile| pile.into_iter().all(Face::is_down)));
+    }
+
+    #[test]
+    fn turn() {
+        let mut game = Clock::new(());
+        game.stock.vec.clear();
+        game.piles[12].push(Face::Down(card!(H 5)));
+        game.piles[4].push(Face::Down(card!(C 2)));
+        game.play(&Play::Turn(rank!(K)));
+        assert!(game.piles[12].is_empty());
+        assert_eq!(Some(Face::Up(card!(H 5))), game.piles[4].get(0));
+        assert_eq!(Some(Face::Down(card!(C 2))), game.piles[4].top());
+        assert_eq!(Some(Play::Turn(rank!(5))), game.next_play());
+    }
+
+    #[test]
+    fn lost() {
+        let mut game = Clock::new(());
+        game.stock.vec.clear();
+        game.piles[12].push(Face::Down(card!(H K)));
+        game.piles[4].push(Face::Down(card!(C 2)));
+        game.play(&Play::Turn(rank!(K)));
+        assert!(game.is_lost());
+        assert!(!game.is_won());
+    }
+
+    #[test]
+    fn autoplay() {
+        let mut game = Clock::new(());
+        game.deal();
+        let won = game.autoplay();
+        assert_eq!(won, game.is_won());
+        assert!(won || game.is_lost());
+        assert_eq!(4, game.piles[12].into_iter().filter(|face| face.is_up()).count());
+    }
+
+    #[test]
+    fn autoplay_won() {
+        let mut game = Clock::new(());
+        game.stock.vec.clear();
+        game.piles[12].push(Face::Down(card!(H K)));
+        game.piles[12].push(Face::Down(card!(H A)));
+        game.piles[0].push(Face::Down(card!(C K)));
+        assert!(game.autoplay());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::clock::{Clock, Play};
+
+    #[test]
+    fn turn() {
+        let mut before = Clock::new(());
+        before.deal();
+        let mut after = before.clone();
+        after.play(&Play::Turn(rank!(K)));
+        after.undo(&Play::Turn(rank!(K)));
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn turns() {
+        let mut before = Clock::new(());
+        before.deal();
+        let mut after = before.clone();
+        let mut plays = Vec::new();
+        while let Some(play) = after.next_play() {
+            after.play(&play);
+            plays.push(play);
+        }
+        for play in plays.iter().rev() {
+            after.undo(play);
+        }
+        assert_eq!(before, after);
+    }
+}
@@ -0,0 +1,173 @@
+use card::{Rank, Card, Face, Deck, Pile};
+use game::Game;
+use super::{FortyThieves, Rules, Play};
+
+impl Game for FortyThieves {
+    type Rules = Rules;
+    type Play = Play;
+
+    fn new(rules: Rules) -> Self {
+        FortyThieves {
+            rules: rules,
+            stock: Deck::new().decks(2).pile(),
+            waste: Pile::new(),
+            foundations: vec![Pile::new(); 8],
+            tableau: vec![Pile::new(); rules.columns as usize],
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        if self.rules.aces_out {
+            let (aces, rest): (Vec<Face>, Vec<Face>) = self.stock.vec.drain(..)
+                .partition(|face| face.card().rank == Rank::Ace);
+            self.stock.vec = rest;
+            for (foundation, ace) in self.foundations.iter_mut().zip(aces) {
+                foundation.push(ace.flipped());
+            }
+        }
+        for row in 0..self.rules.cards_per_column {
+            let flip = row >= self.rules.face_down_rows;
+            for pile in &mut self.tableau {
+                self.stock.deal_to(pile, 1, flip);
+            }
+        }
+    }
+
+    fn is_won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.count() == 13)
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::Draw => {
+                !self.stock.is_empty()
+            },
+
+            Play::Reveal(tableau) => {
+                self.tableau.get(tableau as usize)
+                    .and_then(Pile::top)
+                    .as_ref()
+                    .map_or(false, Face::is_down)
+            },
+
+            Play::WasteTableau(tableau) => {
+                self.waste.top().map_or(false, |face| {
+                    self.is_valid_tableau(tableau, face.card())
+                })
+            },
+
+            Play::WasteFoundation(foundation) => {
+                self.waste.top().map_or(false, |face| {
+                    self.is_valid_foundation(foundation, face.card())
+                })
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.tableau.get(tableau as usize).and_then(Pile::top).map_or(false, |face| {
+                    face.is_up() && self.is_valid_foundation(foundation, face.card())
+                })
+            },
+
+            Play::TableauTableau(src, count, dest) => {
+                src != dest
+                    && (count == 1 || self.rules.sequences)
+                    && self.tableau.get(src as usize).map_or(false, |pile| {
+                        self.rules.build.is_sequence(pile, count)
+                    })
+                    && self.tableau[src as usize].get_back(count as usize).map_or(false, |face| {
+                        self.is_valid_tableau(dest, face.card())
+                    })
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::Draw => {
+                self.stock.deal_to(&mut self.waste, 1, true);
+            },
+
+            Play::Reveal(tableau) => {
+                self.tableau[tableau as usize].flip_top();
+            },
+
+            Play::WasteTableau(tableau) => {
+                self.waste.move_to(&mut self.tableau[tableau as usize], 1);
+            },
+
+            Play::WasteFoundation(foundation) => {
+                self.waste.move_to(&mut self.foundations[foundation as usize], 1);
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.tableau[tableau as usize].move_to(
+                    &mut self.foundations[foundation as usize],
+                    1,
+                );
+            },
+
+            Play::TableauTableau(src, count, dest) => {
+                if src < dest {
+                    let (left, right) = self.tableau.split_at_mut(dest as usize);
+                    left[src as usize].move_to(&mut right[0], count as usize);
+                } else {
+                    let (left, right) = self.tableau.split_at_mut(src as usize);
+                    right[0].move_to(&mut left[dest as usize], count as usize);
+                }
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::Draw => {
+                self.waste.deal_to(&mut self.stock, 1, true);
+            },
+
+            Play::Reveal(_) => self.play(play),
+
+            Play::WasteTableau(tableau) => {
+                self.tableau[tableau as usize].move_to(&mut self.waste, 1);
+            },
+
+            Play::WasteFoundation(foundation) => {
+                self.foundations[foundation as usize].move_to(&mut self.waste, 1);
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.foundations[foundation as usize].move_to(
+                    &mut self.tableau[tableau as usize],
+                    1,
+                );
+            },
+
+            Play::TableauTableau(src, count, dest) => {
+                self.play(&Play::TableauTableau(dest, count, src));
+            },
+        }
+    }
+}
+
+impl FortyThieves {
+    fn is_valid_foundation(&self, foundation: u8, card: Card) -> bool {
+        let foundation = match self.foundations.get(foundation as usize) {
+            Some(foundation) => foundation,
+            None => return false,
+        };
+        if foundation.is_empty() {
+            card.rank == Rank::Ace
+        } else {
+            let top = foundation.top().unwrap().card();
+            card.suit == top.suit && card.rank.pred() == Some(top.rank)
+        }
+    }
+
+    fn is_valid_tableau(&self, tableau: u8, card: Card) -> bool {
+        match self.tableau.get(tableau as usize).map(Pile::top) {
+            None => false,
+            Some(None) => true,
+            Some(Some(face)) => face.is_up() && self.rules.build.is_built(face.card(), card),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Forty Thieves solitaire, also known as Napoleon at St Helena, and its relatives.
+
+use card::Pile;
+
+pub use game::build::Build;
+
+/// Forty Thieves solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FortyThieves {
+    rules: Rules,
+    stock: Pile,
+    waste: Pile,
+    foundations: Vec<Pile>,
+    tableau: Vec<Pile>,
+}
+
+/// Forty Thieves variation rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Number of tableau columns.
+    pub columns: u8,
+
+    /// Number of cards dealt to each column.
+    pub cards_per_column: u8,
+
+    /// Number of rows dealt face-down.
+    pub face_down_rows: u8,
+
+    /// How cards are built down on the tableau.
+    pub build: Build,
+
+    /// Aces are placed on the foundations before dealing.
+    pub aces_out: bool,
+
+    /// Built sequences may be moved as a unit rather than one card at a time.
+    pub sequences: bool,
+}
+
+impl Rules {
+    /// Forty Thieves: ten columns of four, built down in suit.
+    pub fn forty_thieves() -> Self {
+        Rules {
+            columns: 10,
+            cards_per_column: 4,
+            face_down_rows: 0,
+            build: Build::Suit,
+            aces_out: false,
+            sequences: false,
+        }
+    }
+
+    /// Lucas: aces out, thirteen columns of three, built down in suit.
+    pub fn lucas() -> Self {
+        Rules {
+            columns: 13,
+            cards_per_column: 3,
+            aces_out: true,
+            ..Rules::forty_thieves()
+        }
+    }
+
+    /// Number Ten: ten columns of four with two rows face-down, built down in alternate colors,
+    /// sequences moved as a unit.
+    pub fn number_ten() -> Self {
+        Rules {
+            face_down_rows: 2,
+            build: Build::AlternateColor,
+            sequences: true,
+            ..Rules::forty_thieves()
+        }
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::forty_thieves()
+    }
+}
+
+/// Forty Thieves play.
+///
+/// Foundations and tableaux are numbered from zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Draw from stock into waste.
+    Draw,
+
+    /// Reveal the top card of a tableau pile.
+    Reveal(u8),
+
+    /// Move top card of waste to tableau.
+    WasteTableau(u8),
+
+    /// Move top card of waste to foundation.
+    WasteFoundation(u8),
+
+    /// Move top card of tableau to foundation.
+    TableauFoundation(u8, u8),
+
+    /// Move cards from tableau to tableau.
+    TableauTableau(u8, u8, u8),
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
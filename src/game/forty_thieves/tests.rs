@@ -0,0 +1,202 @@
+mod is_valid {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::forty_thieves::{FortyThieves, Rules, Play};
+
+    #[test]
+    fn valid_draw_full_stock() {
+        let game = FortyThieves::new(Rules::default());
+        assert!(game.is_valid(&Play::Draw));
+    }
+
+    #[test]
+    fn invalid_draw_empty_stock() {
+        let mut game = FortyThieves::new(Rules::default());
+        game.stock = Pile::new();
+        assert!(!game.is_valid(&Play::Draw));
+    }
+
+    #[test]
+    fn valid_reveal() {
+        let mut game = FortyThieves::new(Rules::number_ten());
+        game.tableau[0].push(Face::Down(card!(S 5)));
+        assert!(game.is_valid(&Play::Reveal(0)));
+    }
+
+    #[test]
+    fn invalid_reveal_out_of_range() {
+        let game = FortyThieves::new(Rules::default());
+        assert!(!game.is_valid(&Play::Reveal(10)));
+    }
+
+    #[test]
+    fn valid_waste_tableau_empty() {
+        let mut game = FortyThieves::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 5)));
+        assert!(game.is_valid(&Play::WasteTableau(0)));
+    }
+
+    #[test]
+    fn valid_waste_tableau_suit() {
+        let mut game = FortyThieves::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 5)));
+        game.tableau[0].push(Face::Up(card!(H 6)));
+        assert!(game.is_valid(&Play::WasteTableau(0)));
+    }
+
+    #[test]
+    fn invalid_waste_tableau_non_suit() {
+        let mut game = FortyThieves::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 5)));
+        game.tableau[0].push(Face::Up(card!(D 6)));
+        assert!(!game.is_valid(&Play::WasteTableau(0)));
+    }
+
+    #[test]
+    fn valid_waste_tableau_alternate_color() {
+        let mut game = FortyThieves::new(Rules::number_ten());
+        game.waste.push(Face::Up(card!(H 5)));
+        game.tableau[0].push(Face::Up(card!(S 6)));
+        assert!(game.is_valid(&Play::WasteTableau(0)));
+    }
+
+    #[test]
+    fn invalid_waste_tableau_face_down() {
+        let mut game = FortyThieves::new(Rules::number_ten());
+        game.waste.push(Face::Up(card!(H 5)));
+        game.tableau[0].push(Face::Down(card!(S 6)));
+        assert!(!game.is_valid(&Play::WasteTableau(0)));
+    }
+
+    #[test]
+    fn valid_waste_foundation_ace() {
+        let mut game = FortyThieves::new(Rules::default());
+        game.waste.push(Face::Up(card!(H A)));
+        assert!(game.is_valid(&Play::WasteFoundation(7)));
+    }
+
+    #[test]
+    fn invalid_waste_foundation_out_of_range() {
+        let mut game = FortyThieves::new(Rules::default());
+        game.waste.push(Face::Up(card!(H A)));
+        assert!(!game.is_valid(&Play::WasteFoundation(8)));
+    }
+
+    #[test]
+    fn valid_tableau_foundation_succ() {
+        let mut game = FortyThieves::new(Rules::default());
+        game.foundations[0].push(Face::Up(card!(C A)));
+        game.tableau[0].push(Face::Up(card!(C 2)));
+        assert!(game.is_valid(&Play::TableauFoundation(0, 0)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau_one() {
+        let mut game = FortyThieves::new(Rules::default());
+        game.tableau[0].push(Face::Up(card!(C 2)));
+        game.tableau[1].push(Face::Up(card!(C 3)));
+        assert!(game.is_valid(&Play::TableauTableau(0, 1, 1)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_sequence() {
+        let mut game = FortyThieves::new(Rules::default());
+        game.tableau[0].push(Face::Up(card!(C 3)));
+        game.tableau[0].push(Face::Up(card!(C 2)));
+        game.tableau[1].push(Face::Up(card!(C 4)));
+        assert!(!game.is_valid(&Play::TableauTableau(0, 2, 1)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau_sequence() {
+        let mut game = FortyThieves::new(Rules::number_ten());
+        game.tableau[0].push(Face::Up(card!(C 3)));
+        game.tableau[0].push(Face::Up(card!(H 2)));
+        game.tableau[1].push(Face::Up(card!(D 4)));
+        assert!(game.is_valid(&Play::TableauTableau(0, 2, 1)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_broken_sequence() {
+        let mut game = FortyThieves::new(Rules::number_ten());
+        game.tableau[0].push(Face::Up(card!(C 3)));
+        game.tableau[0].push(Face::Up(card!(S 2)));
+        game.tableau[1].push(Face::Up(card!(D 4)));
+        assert!(!game.is_valid(&Play::TableauTableau(0, 2, 1)));
+    }
+}
+
+mod play {
+    use card::Face;
+    use game::Game;
+    use game::forty_thieves::{FortyThieves, Rules, Play};
+
+    #[test]
+    fn deal_forty_thieves() {
+        let mut game = FortyThieves::new(Rules::forty_thieves());
+        game.deal();
+        assert_eq!(10, game.tableau.len());
+        assert!(game.tableau.iter().all(|pile| pile.count() == 4));
+        assert!(game.tableau.iter().all(|pile| pile.into_iter().all(Face::is_up)));
+        assert_eq!(64, game.stock.count());
+    }
+
+    #[test]
+    fn deal_lucas() {
+        let mut game = FortyThieves::new(Rules::lucas());
+        game.deal();
+        assert_eq!(13, game.tableau.len());
+        assert!(game.tableau.iter().all(|pile| pile.count() == 3));
+        assert!(game.foundations.iter().all(|pile| pile.count() == 1));
+        assert!(game.foundations.iter().all(|pile| pile.top().unwrap().is_up()));
+        assert_eq!(57, game.stock.count());
+    }
+
+    #[test]
+    fn deal_number_ten() {
+        let mut game = FortyThieves::new(Rules::number_ten());
+        game.deal();
+        assert!(game.tableau.iter().all(|pile| {
+            pile.get(0).unwrap().is_down()
+                && pile.get(1).unwrap().is_down()
+                && pile.get(2).unwrap().is_up()
+        }));
+    }
+
+    #[test]
+    fn tableau_tableau() {
+        let mut game = FortyThieves::new(Rules::default());
+        game.deal();
+        let card = game.tableau[9].top();
+        game.play(&Play::TableauTableau(9, 1, 0));
+        assert_eq!(card, game.tableau[0].top());
+        assert_eq!(3, game.tableau[9].count());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::forty_thieves::{FortyThieves, Rules, Play};
+
+    macro_rules! undo_test {
+        ($name:ident, $play:expr) => {
+            #[test]
+            fn $name() {
+                let mut before = FortyThieves::new(Rules::number_ten());
+                before.deal();
+                before.play(&Play::Draw);
+                let mut after = before.clone();
+                after.play(&$play);
+                after.undo(&$play);
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    undo_test!(draw, Play::Draw);
+    undo_test!(reveal, Play::Reveal(0));
+    undo_test!(waste_tableau, Play::WasteTableau(0));
+    undo_test!(waste_foundation, Play::WasteFoundation(0));
+    undo_test!(tableau_foundation, Play::TableauFoundation(0, 0));
+    undo_test!(tableau_tableau, Play::TableauTableau(0, 2, 9));
+}
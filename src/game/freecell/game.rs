@@ -0,0 +1,181 @@
+use card::{Rank, Card, Face, Set, Pile};
+use game::Game;
+use super::{FreeCell, Rules, Empty, Play, Foundation};
+
+impl Game for FreeCell {
+    type Rules = Rules;
+    type Play = Play;
+
+    fn new(rules: Rules) -> Self {
+        assert!(rules.columns > 0, "no tableau columns");
+        assert!(rules.reserve <= rules.cells, "more reserve cards than cells");
+        FreeCell {
+            rules: rules,
+            stock: Set::new().map(Face::Up).collect(),
+            cells: vec![None; rules.cells as usize],
+            foundations: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
+            tableau: vec![Pile::new(); rules.columns as usize],
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        let reserve = self.rules.reserve as usize;
+        let mut i = 0;
+        while self.stock.count() > reserve {
+            let columns = self.tableau.len();
+            self.stock.move_to(&mut self.tableau[i % columns], 1);
+            i += 1;
+        }
+        for cell in &mut self.cells {
+            *cell = self.stock.pop().as_ref().map(Face::card);
+        }
+    }
+
+    fn is_won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.count() == 13)
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::TableauCell(tableau, cell) => {
+                self.tableau.get(tableau as usize).map_or(false, |pile| !pile.is_empty())
+                    && self.cells.get(cell as usize).map_or(false, Option::is_none)
+            },
+
+            Play::CellTableau(cell, tableau) => {
+                self.cell(cell).map_or(false, |card| self.is_valid_tableau(tableau, card))
+            },
+
+            Play::CellFoundation(cell, foundation) => {
+                self.cell(cell).map_or(false, |card| self.is_valid_foundation(foundation, card))
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.tableau.get(tableau as usize).and_then(Pile::top).map_or(false, |face| {
+                    self.is_valid_foundation(foundation, face.card())
+                })
+            },
+
+            Play::TableauTableau(src, count, dest) => {
+                src != dest
+                    && count as usize <= self.capacity(dest)
+                    && self.tableau.get(src as usize).map_or(false, |pile| {
+                        self.rules.build.is_sequence(pile, count)
+                    })
+                    && self.tableau[src as usize].get_back(count as usize).map_or(false, |face| {
+                        self.is_valid_tableau(dest, face.card())
+                    })
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::TableauCell(tableau, cell) => {
+                let face = self.tableau[tableau as usize].pop();
+                self.cells[cell as usize] = face.as_ref().map(Face::card);
+            },
+
+            Play::CellTableau(cell, tableau) => {
+                if let Some(card) = self.cells[cell as usize].take() {
+                    self.tableau[tableau as usize].push(Face::Up(card));
+                }
+            },
+
+            Play::CellFoundation(cell, foundation) => {
+                if let Some(card) = self.cells[cell as usize].take() {
+                    self.foundations[foundation as usize].push(Face::Up(card));
+                }
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.tableau[tableau as usize].move_to(
+                    &mut self.foundations[foundation as usize],
+                    1,
+                );
+            },
+
+            Play::TableauTableau(src, count, dest) => {
+                if src < dest {
+                    let (left, right) = self.tableau.split_at_mut(dest as usize);
+                    left[src as usize].move_to(&mut right[0], count as usize);
+                } else {
+                    let (left, right) = self.tableau.split_at_mut(src as usize);
+                    right[0].move_to(&mut left[dest as usize], count as usize);
+                }
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::TableauCell(tableau, cell) => {
+                self.play(&Play::CellTableau(cell, tableau));
+            },
+
+            Play::CellTableau(cell, tableau) => {
+                self.play(&Play::TableauCell(tableau, cell));
+            },
+
+            Play::CellFoundation(cell, foundation) => {
+                let face = self.foundations[foundation as usize].pop();
+                self.cells[cell as usize] = face.as_ref().map(Face::card);
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.foundations[foundation as usize].move_to(
+                    &mut self.tableau[tableau as usize],
+                    1,
+                );
+            },
+
+            Play::TableauTableau(src, count, dest) => {
+                self.play(&Play::TableauTableau(dest, count, src));
+            },
+        }
+    }
+}
+
+impl FreeCell {
+    /// Returns the number of cards that can be moved as a unit to a tableau, by way of the free
+    /// cells and any empty columns that may be filled by any card.
+    pub fn capacity(&self, dest: u8) -> usize {
+        let cells = self.cells.iter().filter(|cell| cell.is_none()).count();
+        let columns = match self.rules.empty {
+            Empty::Any => {
+                self.tableau.iter()
+                    .enumerate()
+                    .filter(|&(i, pile)| i != dest as usize && pile.is_empty())
+                    .count()
+            },
+            Empty::Kings => 0,
+        };
+        (cells + 1) << columns
+    }
+
+    fn cell(&self, cell: u8) -> Option<Card> {
+        self.cells.get(cell as usize).cloned().unwrap_or(None)
+    }
+
+    fn is_valid_foundation(&self, foundation: Foundation, card: Card) -> bool {
+        let foundation = &self.foundations[foundation as usize];
+        if foundation.is_empty() {
+            card.rank == Rank::Ace
+        } else {
+            let top = foundation.top().unwrap().card();
+            card.suit == top.suit && card.rank.pred() == Some(top.rank)
+        }
+    }
+
+    fn is_valid_tableau(&self, tableau: u8, card: Card) -> bool {
+        match self.tableau.get(tableau as usize).map(Pile::top) {
+            None => false,
+            Some(None) => match self.rules.empty {
+                Empty::Any => true,
+                Empty::Kings => card.rank == Rank::King,
+            },
+            Some(Some(top)) => self.rules.build.is_built(top.card(), card),
+        }
+    }
+}
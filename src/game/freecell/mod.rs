@@ -0,0 +1,125 @@
+//! FreeCell solitaire and its relatives, such as Eight Off and Seahaven Towers.
+
+use card::{Card, Pile};
+
+pub use game::build::Build;
+
+/// FreeCell solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FreeCell {
+    rules: Rules,
+    stock: Pile,
+    cells: Vec<Option<Card>>,
+    foundations: [Pile; 4],
+    tableau: Vec<Pile>,
+}
+
+/// FreeCell variation rules.
+///
+/// `FreeCell::new` panics if there are no columns or more reserve cards than cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Number of cells.
+    pub cells: u8,
+
+    /// Number of cells filled by the deal.
+    pub reserve: u8,
+
+    /// Number of tableau columns.
+    pub columns: u8,
+
+    /// How cards are built down on the tableau.
+    pub build: Build,
+
+    /// Which cards may be moved to an empty column.
+    pub empty: Empty,
+}
+
+impl Rules {
+    /// FreeCell: four cells, eight columns, built down in alternate colors.
+    pub fn freecell() -> Self {
+        Rules {
+            cells: 4,
+            reserve: 0,
+            columns: 8,
+            build: Build::AlternateColor,
+            empty: Empty::Any,
+        }
+    }
+
+    /// Eight Off: eight cells with four filled, eight columns of six, built down in suit, only
+    /// kings to empty columns.
+    pub fn eight_off() -> Self {
+        Rules {
+            cells: 8,
+            reserve: 4,
+            columns: 8,
+            build: Build::Suit,
+            empty: Empty::Kings,
+        }
+    }
+
+    /// Seahaven Towers: four cells with two filled, ten columns of five, built down in suit,
+    /// only kings to empty columns.
+    pub fn seahaven_towers() -> Self {
+        Rules {
+            cells: 4,
+            reserve: 2,
+            columns: 10,
+            build: Build::Suit,
+            empty: Empty::Kings,
+        }
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::freecell()
+    }
+}
+
+/// Empty column rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Empty {
+    /// Any card may be moved to an empty column.
+    Any,
+
+    /// Only kings may be moved to an empty column.
+    Kings,
+}
+
+/// FreeCell play.
+///
+/// Cells and tableaux are numbered from zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Move top card of tableau to cell.
+    TableauCell(u8, u8),
+
+    /// Move card from cell to tableau.
+    CellTableau(u8, u8),
+
+    /// Move card from cell to foundation.
+    CellFoundation(u8, Foundation),
+
+    /// Move top card of tableau to foundation.
+    TableauFoundation(u8, Foundation),
+
+    /// Move cards from tableau to tableau, using free cells and columns as needed.
+    TableauTableau(u8, u8, u8),
+}
+
+/// Foundations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Foundation {
+    One,
+    Two,
+    Three,
+    Four,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
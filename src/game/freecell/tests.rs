@@ -0,0 +1,242 @@
+mod new {
+    use game::Game;
+    use game::freecell::{FreeCell, Rules};
+
+    #[test]
+    #[should_panic]
+    fn no_columns() {
+        let _ = FreeCell::new(Rules { columns: 0, ..Rules::freecell() });
+    }
+
+    #[test]
+    #[should_panic]
+    fn reserve_exceeds_cells() {
+        let _ = FreeCell::new(Rules { reserve: 5, ..Rules::freecell() });
+    }
+
+    #[test]
+    fn reserve_fills_cells() {
+        let mut game = FreeCell::new(Rules { reserve: 4, ..Rules::freecell() });
+        game.deal();
+        assert!(game.stock.is_empty());
+        assert!(game.cells.iter().all(Option::is_some));
+    }
+}
+
+mod is_valid {
+    use card::Face;
+    use game::Game;
+    use game::freecell::{FreeCell, Rules, Play, Foundation};
+
+    #[test]
+    fn valid_tableau_cell() {
+        let mut game = FreeCell::new(Rules::eight_off());
+        game.tableau[0].push(Face::Up(card!(S 5)));
+        assert!(game.is_valid(&Play::TableauCell(0, 7)));
+    }
+
+    #[test]
+    fn invalid_tableau_cell_full() {
+        let mut game = FreeCell::new(Rules::eight_off());
+        game.tableau[0].push(Face::Up(card!(S 5)));
+        game.cells[7] = Some(card!(H 2));
+        assert!(!game.is_valid(&Play::TableauCell(0, 7)));
+    }
+
+    #[test]
+    fn invalid_tableau_cell_out_of_range() {
+        let mut game = FreeCell::new(Rules::seahaven_towers());
+        game.tableau[0].push(Face::Up(card!(S 5)));
+        assert!(!game.is_valid(&Play::TableauCell(0, 4)));
+    }
+
+    #[test]
+    fn valid_cell_tableau_suit() {
+        let mut game = FreeCell::new(Rules::eight_off());
+        game.cells[0] = Some(card!(S 5));
+        game.tableau[0].push(Face::Up(card!(S 6)));
+        assert!(game.is_valid(&Play::CellTableau(0, 0)));
+    }
+
+    #[test]
+    fn invalid_cell_tableau_non_suit() {
+        let mut game = FreeCell::new(Rules::eight_off());
+        game.cells[0] = Some(card!(S 5));
+        game.tableau[0].push(Face::Up(card!(H 6)));
+        assert!(!game.is_valid(&Play::CellTableau(0, 0)));
+    }
+
+    #[test]
+    fn valid_cell_tableau_alternate_color() {
+        let mut game = FreeCell::new(Rules::freecell());
+        game.cells[0] = Some(card!(S 5));
+        game.tableau[0].push(Face::Up(card!(H 6)));
+        assert!(game.is_valid(&Play::CellTableau(0, 0)));
+    }
+
+    #[test]
+    fn valid_cell_tableau_empty_king() {
+        let mut game = FreeCell::new(Rules::seahaven_towers());
+        game.cells[0] = Some(card!(S K));
+        assert!(game.is_valid(&Play::CellTableau(0, 0)));
+    }
+
+    #[test]
+    fn invalid_cell_tableau_empty_non_king() {
+        let mut game = FreeCell::new(Rules::seahaven_towers());
+        game.cells[0] = Some(card!(S Q));
+        assert!(!game.is_valid(&Play::CellTableau(0, 0)));
+    }
+
+    #[test]
+    fn valid_cell_tableau_empty_any() {
+        let mut game = FreeCell::new(Rules::freecell());
+        game.cells[0] = Some(card!(S Q));
+        assert!(game.is_valid(&Play::CellTableau(0, 0)));
+    }
+
+    #[test]
+    fn valid_cell_foundation_ace() {
+        let mut game = FreeCell::new(Rules::eight_off());
+        game.cells[3] = Some(card!(D A));
+        assert!(game.is_valid(&Play::CellFoundation(3, Foundation::One)));
+    }
+
+    #[test]
+    fn invalid_cell_foundation_empty() {
+        let game = FreeCell::new(Rules::eight_off());
+        assert!(!game.is_valid(&Play::CellFoundation(3, Foundation::One)));
+    }
+
+    #[test]
+    fn valid_tableau_foundation_succ() {
+        let mut game = FreeCell::new(Rules::eight_off());
+        game.foundations[0].push(Face::Up(card!(D A)));
+        game.tableau[0].push(Face::Up(card!(D 2)));
+        assert!(game.is_valid(&Play::TableauFoundation(0, Foundation::One)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau_sequence() {
+        let mut game = FreeCell::new(Rules::eight_off());
+        game.tableau[0].push(Face::Up(card!(D 5)));
+        game.tableau[0].push(Face::Up(card!(D 4)));
+        game.tableau[1].push(Face::Up(card!(D 6)));
+        assert!(game.is_valid(&Play::TableauTableau(0, 2, 1)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_capacity() {
+        let mut game = FreeCell::new(Rules::seahaven_towers());
+        for cell in &mut game.cells {
+            *cell = Some(card!(H A));
+        }
+        game.tableau[0].push(Face::Up(card!(D 5)));
+        game.tableau[0].push(Face::Up(card!(D 4)));
+        game.tableau[1].push(Face::Up(card!(D 6)));
+        assert!(!game.is_valid(&Play::TableauTableau(0, 2, 1)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_broken_sequence() {
+        let mut game = FreeCell::new(Rules::eight_off());
+        game.tableau[0].push(Face::Up(card!(D 5)));
+        game.tableau[0].push(Face::Up(card!(C 4)));
+        game.tableau[1].push(Face::Up(card!(D 6)));
+        assert!(!game.is_valid(&Play::TableauTableau(0, 2, 1)));
+    }
+}
+
+mod capacity {
+    use game::Game;
+    use game::freecell::{FreeCell, Rules};
+
+    #[test]
+    fn free_cells() {
+        let mut game = FreeCell::new(Rules::seahaven_towers());
+        game.deal();
+        assert_eq!(3, game.capacity(0));
+    }
+
+    #[test]
+    fn empty_columns_any() {
+        let game = FreeCell::new(Rules::freecell());
+        assert_eq!(5 << 7, game.capacity(0));
+    }
+
+    #[test]
+    fn empty_columns_kings() {
+        let game = FreeCell::new(Rules::eight_off());
+        assert_eq!(9, game.capacity(0));
+    }
+}
+
+mod play {
+    use game::Game;
+    use game::freecell::{FreeCell, Rules, Play};
+
+    #[test]
+    fn deal_freecell() {
+        let mut game = FreeCell::new(Rules::freecell());
+        game.deal();
+        assert!(game.stock.is_empty());
+        assert!(game.tableau[..4].iter().all(|pile| pile.count() == 7));
+        assert!(game.tableau[4..].iter().all(|pile| pile.count() == 6));
+        assert!(game.cells.iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn deal_eight_off() {
+        let mut game = FreeCell::new(Rules::eight_off());
+        game.deal();
+        assert!(game.stock.is_empty());
+        assert!(game.tableau.iter().all(|pile| pile.count() == 6));
+        assert_eq!(4, game.cells.iter().filter(|cell| cell.is_some()).count());
+        assert!(game.cells[4..].iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn deal_seahaven_towers() {
+        let mut game = FreeCell::new(Rules::seahaven_towers());
+        game.deal();
+        assert!(game.stock.is_empty());
+        assert_eq!(10, game.tableau.len());
+        assert!(game.tableau.iter().all(|pile| pile.count() == 5));
+        assert_eq!(2, game.cells.iter().filter(|cell| cell.is_some()).count());
+    }
+
+    #[test]
+    fn tableau_cell() {
+        let mut game = FreeCell::new(Rules::eight_off());
+        game.deal();
+        let card = game.tableau[0].top().map(|face| face.card());
+        game.play(&Play::TableauCell(0, 5));
+        assert_eq!(card, game.cells[5]);
+        assert_eq!(5, game.tableau[0].count());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::freecell::{FreeCell, Rules, Play, Foundation};
+
+    macro_rules! undo_test {
+        ($name:ident, $play:expr) => {
+            #[test]
+            fn $name() {
+                let mut before = FreeCell::new(Rules::eight_off());
+                before.deal();
+                let mut after = before.clone();
+                after.play(&$play);
+                after.undo(&$play);
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    undo_test!(tableau_cell, Play::TableauCell(0, 5));
+    undo_test!(cell_tableau, Play::CellTableau(0, 1));
+    undo_test!(cell_foundation, Play::CellFoundation(1, Foundation::One));
+    undo_test!(tableau_foundation, Play::TableauFoundation(2, Foundation::Two));
+    undo_test!(tableau_tableau, Play::TableauTableau(3, 2, 4));
+}
@@ -0,0 +1,138 @@
+use card::{Rank, Face, Set, Grid, Pile};
+use game::Game;
+use super::{Gaps, Rules, Play};
+
+impl Game for Gaps {
+    type Rules = Rules;
+    type Play = Play;
+
+    fn new(rules: Rules) -> Self {
+        Gaps {
+            rules: rules,
+            stock: Set::new().map(Face::Down).collect(),
+            aces: Pile::new(),
+            grid: Grid::new(4, 13),
+            redeals: Vec::new(),
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        for row in 0..self.grid.rows() {
+            for column in 0..self.grid.columns() {
+                let face = match self.stock.pop() {
+                    Some(face) => face.flipped(),
+                    None => return,
+                };
+                if face.card().rank == Rank::Ace {
+                    self.aces.push(face);
+                } else {
+                    let _ = self.grid.set(row, column, Some(face));
+                }
+            }
+        }
+    }
+
+    fn is_won(&self) -> bool {
+        (0..self.grid.rows()).all(|row| self.sequence(row) == self.grid.columns() - 1)
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::Move(src, dest) => {
+                let (row, column) = (dest.row as usize, dest.column as usize);
+                if row >= self.grid.rows() || column >= self.grid.columns() {
+                    return false;
+                }
+                if self.grid.get(row, column).is_some() {
+                    return false;
+                }
+                self.grid.get(src.row as usize, src.column as usize).map_or(false, |face| {
+                    let card = face.card();
+                    if column == 0 {
+                        card.rank == Rank::Two
+                    } else {
+                        self.grid.get(row, column - 1).map_or(false, |left| {
+                            let left = left.card();
+                            card.suit == left.suit && left.rank.succ() == Some(card.rank)
+                        })
+                    }
+                })
+            },
+
+            Play::Redeal => {
+                self.redeals.len() < self.rules.redeals as usize
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::Move(src, dest) => {
+                let face = self.grid.take(src.row as usize, src.column as usize);
+                let _ = self.grid.set(dest.row as usize, dest.column as usize, face);
+            },
+
+            Play::Redeal => {
+                self.redeals.push(self.grid.clone());
+
+                let mut pile = Pile::new();
+                for row in 0..self.grid.rows() {
+                    let sequence = self.sequence(row);
+                    for cell in &mut self.grid.row_mut(row)[sequence..] {
+                        if let Some(face) = cell.take() {
+                            pile.push(face);
+                        }
+                    }
+                }
+
+                pile.shuffle();
+                for row in 0..self.grid.rows() {
+                    let sequence = self.sequence(row);
+                    for cell in self.grid.row_mut(row).iter_mut().skip(sequence + 1) {
+                        *cell = pile.pop();
+                    }
+                }
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::Move(src, dest) => {
+                self.play(&Play::Move(dest, src));
+            },
+
+            Play::Redeal => {
+                if let Some(grid) = self.redeals.pop() {
+                    self.grid = grid;
+                }
+            },
+        }
+    }
+}
+
+impl Gaps {
+    /// Returns the number of redeals remaining.
+    pub fn redeals(&self) -> u8 {
+        self.rules.redeals.saturating_sub(self.redeals.len() as u8)
+    }
+
+    /// Returns the length of the sequence from two upward in one suit at the start of a row.
+    pub fn sequence(&self, row: usize) -> usize {
+        let cells = self.grid.row(row);
+        let first = match cells.first().cloned().unwrap_or(None) {
+            Some(face) if face.card().rank == Rank::Two => face.card(),
+            _ => return 0,
+        };
+        1 + cells.windows(2)
+            .take_while(|pair| match (pair[0], pair[1]) {
+                (Some(left), Some(right)) => {
+                    right.card().suit == first.suit
+                        && left.card().rank.succ() == Some(right.card().rank)
+                },
+                _ => false,
+            })
+            .count()
+    }
+}
@@ -0,0 +1,50 @@
+//! Gaps solitaire, also known as Montana.
+
+use card::{Grid, Pile};
+
+/// Gaps solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gaps {
+    rules: Rules,
+    stock: Pile,
+    aces: Pile,
+    grid: Grid,
+    redeals: Vec<Grid>,
+}
+
+/// Gaps variation rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Number of times the cards out of sequence may be gathered and redealt.
+    pub redeals: u8,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules { redeals: 3 }
+    }
+}
+
+/// Gaps play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Move a card into a gap.
+    Move(Position, Position),
+
+    /// Gather the cards out of sequence, shuffle them and deal them back, leaving a gap after
+    /// each sequence.
+    Redeal,
+}
+
+/// Position in the grid, numbered from zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub struct Position {
+    pub row: u8,
+    pub column: u8,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
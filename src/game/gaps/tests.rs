@@ -0,0 +1,197 @@
+use card::{Suit, Rank, Card, Face};
+use game::gaps::Gaps;
+
+fn fill_row(game: &mut Gaps, row: usize, suit: Suit) {
+    let mut rank = Rank::Two;
+    for column in 0..12 {
+        let _ = game.grid.set(row, column, Some(Face::Up(Card::new(suit, rank))));
+        rank = rank.succ().unwrap_or(rank);
+    }
+}
+
+mod is_valid {
+    use card::Face;
+    use game::Game;
+    use game::gaps::{Gaps, Rules, Play, Position};
+
+    fn position(row: u8, column: u8) -> Position {
+        Position { row: row, column: column }
+    }
+
+    #[test]
+    fn valid_move_succ() {
+        let mut game = Gaps::new(Rules::default());
+        let _ = game.grid.set(0, 4, Some(Face::Up(card!(H 6))));
+        let _ = game.grid.set(2, 7, Some(Face::Up(card!(H 7))));
+        assert!(game.is_valid(&Play::Move(position(2, 7), position(0, 5))));
+    }
+
+    #[test]
+    fn invalid_move_non_suit() {
+        let mut game = Gaps::new(Rules::default());
+        let _ = game.grid.set(0, 4, Some(Face::Up(card!(H 6))));
+        let _ = game.grid.set(2, 7, Some(Face::Up(card!(D 7))));
+        assert!(!game.is_valid(&Play::Move(position(2, 7), position(0, 5))));
+    }
+
+    #[test]
+    fn invalid_move_non_succ() {
+        let mut game = Gaps::new(Rules::default());
+        let _ = game.grid.set(0, 4, Some(Face::Up(card!(H 6))));
+        let _ = game.grid.set(2, 7, Some(Face::Up(card!(H 8))));
+        assert!(!game.is_valid(&Play::Move(position(2, 7), position(0, 5))));
+    }
+
+    #[test]
+    fn invalid_move_after_king() {
+        let mut game = Gaps::new(Rules::default());
+        let _ = game.grid.set(0, 4, Some(Face::Up(card!(H K))));
+        let _ = game.grid.set(2, 7, Some(Face::Up(card!(H 2))));
+        assert!(!game.is_valid(&Play::Move(position(2, 7), position(0, 5))));
+    }
+
+    #[test]
+    fn invalid_move_after_gap() {
+        let mut game = Gaps::new(Rules::default());
+        let _ = game.grid.set(2, 7, Some(Face::Up(card!(H 7))));
+        assert!(!game.is_valid(&Play::Move(position(2, 7), position(0, 5))));
+    }
+
+    #[test]
+    fn valid_move_two_leftmost() {
+        let mut game = Gaps::new(Rules::default());
+        let _ = game.grid.set(2, 7, Some(Face::Up(card!(S 2))));
+        assert!(game.is_valid(&Play::Move(position(2, 7), position(1, 0))));
+    }
+
+    #[test]
+    fn invalid_move_non_two_leftmost() {
+        let mut game = Gaps::new(Rules::default());
+        let _ = game.grid.set(2, 7, Some(Face::Up(card!(S 3))));
+        assert!(!game.is_valid(&Play::Move(position(2, 7), position(1, 0))));
+    }
+
+    #[test]
+    fn invalid_move_non_gap() {
+        let mut game = Gaps::new(Rules::default());
+        let _ = game.grid.set(0, 0, Some(Face::Up(card!(H 5))));
+        let _ = game.grid.set(2, 7, Some(Face::Up(card!(S 2))));
+        assert!(!game.is_valid(&Play::Move(position(2, 7), position(0, 0))));
+    }
+
+    #[test]
+    fn invalid_move_out_of_range() {
+        let mut game = Gaps::new(Rules::default());
+        let _ = game.grid.set(0, 12, Some(Face::Up(card!(H 5))));
+        let _ = game.grid.set(2, 7, Some(Face::Up(card!(H 6))));
+        assert!(!game.is_valid(&Play::Move(position(2, 7), position(0, 13))));
+    }
+
+    #[test]
+    fn valid_redeal() {
+        let game = Gaps::new(Rules::default());
+        assert!(game.is_valid(&Play::Redeal));
+    }
+
+    #[test]
+    fn invalid_redeal_exhausted() {
+        let mut game = Gaps::new(Rules { redeals: 1 });
+        game.deal();
+        game.play(&Play::Redeal);
+        assert!(!game.is_valid(&Play::Redeal));
+    }
+}
+
+mod play {
+    use card::{Rank, Face};
+    use game::Game;
+    use game::gaps::{Gaps, Rules, Play, Position};
+    use super::fill_row;
+
+    #[test]
+    fn deal() {
+        let mut game = Gaps::new(Rules::default());
+        game.deal();
+        assert!(game.stock.is_empty());
+        assert_eq!(4, game.aces.count());
+        assert_eq!(4, game.grid.gaps().len());
+    }
+
+    #[test]
+    fn move_card() {
+        let mut game = Gaps::new(Rules::default());
+        let _ = game.grid.set(2, 7, Some(Face::Up(card!(S 2))));
+        let play = Play::Move(Position { row: 2, column: 7 }, Position { row: 1, column: 0 });
+        game.play(&play);
+        assert_eq!(None, game.grid.get(2, 7));
+        assert_eq!(Some(Face::Up(card!(S 2))), game.grid.get(1, 0));
+    }
+
+    #[test]
+    fn redeal() {
+        let mut game = Gaps::new(Rules::default());
+        game.deal();
+        let before = game.grid.clone();
+        game.play(&Play::Redeal);
+        assert_eq!(4, game.grid.gaps().len());
+        assert_eq!(2, game.redeals());
+        for row in 0..4 {
+            let sequence = game.sequence(row);
+            assert_eq!(None, game.grid.get(row, sequence));
+            assert_eq!(&before.row(row)[..sequence], &game.grid.row(row)[..sequence]);
+        }
+    }
+
+    #[test]
+    fn redeal_keeps_sequence() {
+        let mut game = Gaps::new(Rules::default());
+        game.deal();
+        fill_row(&mut game, 0, suit!(C));
+        game.play(&Play::Redeal);
+        assert_eq!(12, game.sequence(0));
+        assert_eq!(Some(Rank::King), game.grid.get(0, 11).map(|face| face.card().rank));
+        assert_eq!(None, game.grid.get(0, 12));
+    }
+
+    #[test]
+    fn won() {
+        let mut game = Gaps::new(Rules::default());
+        fill_row(&mut game, 0, suit!(H));
+        fill_row(&mut game, 1, suit!(C));
+        fill_row(&mut game, 2, suit!(D));
+        assert!(!game.is_won());
+        fill_row(&mut game, 3, suit!(S));
+        assert!(game.is_won());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::gaps::{Gaps, Rules, Play, Position};
+
+    #[test]
+    fn move_card() {
+        let mut before = Gaps::new(Rules::default());
+        before.deal();
+        let (row, column) = before.grid.gaps()[0];
+        let src = if column == 0 { (row, 1) } else { (row, 0) };
+        let play = Play::Move(
+            Position { row: src.0 as u8, column: src.1 as u8 },
+            Position { row: row as u8, column: column as u8 },
+        );
+        let mut after = before.clone();
+        after.play(&play);
+        after.undo(&play);
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn redeal() {
+        let mut before = Gaps::new(Rules::default());
+        before.deal();
+        let mut after = before.clone();
+        after.play(&Play::Redeal);
+        after.undo(&Play::Redeal);
+        assert_eq!(before, after);
+    }
+}
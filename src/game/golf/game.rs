@@ -0,0 +1,105 @@
+use card::{Rank, Card, Face, Set, Pile};
+use game::Game;
+use super::{Golf, Rules, Play};
+
+impl Game for Golf {
+    type Rules = Rules;
+    type Play = Play;
+
+    fn new(rules: Rules) -> Self {
+        Golf {
+            rules: rules,
+            stock: Set::new().map(Face::Down).collect(),
+            waste: Pile::new(),
+            tableau: [
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+            ],
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        for pile in &mut self.tableau {
+            self.stock.deal_to(pile, 5, true);
+        }
+        self.stock.deal_to(&mut self.waste, 1, true);
+    }
+
+    fn is_won(&self) -> bool {
+        self.tableau.iter().all(Pile::is_empty)
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::Draw => {
+                !self.stock.is_empty()
+            },
+
+            Play::TableauWaste(tableau) => {
+                self.tableau[tableau as usize].top().map_or(false, |face| {
+                    self.is_valid_waste(face.card())
+                })
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::Draw => {
+                self.stock.deal_to(&mut self.waste, 1, true);
+            },
+
+            Play::TableauWaste(tableau) => {
+                self.tableau[tableau as usize].move_to(&mut self.waste, 1);
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::Draw => {
+                self.waste.deal_to(&mut self.stock, 1, true);
+            },
+
+            Play::TableauWaste(tableau) => {
+                self.waste.move_to(&mut self.tableau[tableau as usize], 1);
+            },
+        }
+    }
+}
+
+impl Golf {
+    /// Returns the score: the number of cards left in the tableau, or, once the tableau is
+    /// cleared, the negated number of cards left in the stock.
+    pub fn score(&self) -> i32 {
+        let tableau = self.tableau.iter().map(Pile::count).sum::<usize>();
+        if tableau == 0 {
+            -(self.stock.count() as i32)
+        } else {
+            tableau as i32
+        }
+    }
+
+    fn is_valid_waste(&self, card: Card) -> bool {
+        let top = match self.waste.top() {
+            Some(face) => face.card(),
+            None => return false,
+        };
+
+        if self.rules.kings_block && top.rank == Rank::King {
+            return false;
+        }
+
+        if self.rules.wrap {
+            card.rank.succ_wrapping() == top.rank || card.rank.pred_wrapping() == top.rank
+        } else {
+            card.rank.succ() == Some(top.rank) || card.rank.pred() == Some(top.rank)
+        }
+    }
+}
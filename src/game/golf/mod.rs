@@ -0,0 +1,59 @@
+//! Golf solitaire.
+
+use card::Pile;
+
+/// Golf solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Golf {
+    rules: Rules,
+    stock: Pile,
+    waste: Pile,
+    tableau: [Pile; 7],
+}
+
+/// Golf variation rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Kings and aces are adjacent, so either may be played on the other.
+    pub wrap: bool,
+
+    /// No card may be played on a king.
+    pub kings_block: bool,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            wrap: false,
+            kings_block: true,
+        }
+    }
+}
+
+/// Golf play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Draw from stock into waste.
+    Draw,
+
+    /// Move top card of tableau to waste.
+    TableauWaste(Tableau),
+}
+
+/// Tableaux.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Tableau {
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,175 @@
+mod is_valid {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::golf::{Golf, Rules, Play, Tableau};
+
+    #[test]
+    fn valid_draw_full_stock() {
+        let game = Golf::new(Rules::default());
+        assert!(game.is_valid(&Play::Draw));
+    }
+
+    #[test]
+    fn invalid_draw_empty_stock() {
+        let mut game = Golf::new(Rules::default());
+        game.stock = Pile::new();
+        assert!(!game.is_valid(&Play::Draw));
+    }
+
+    #[test]
+    fn valid_tableau_waste_succ() {
+        let mut game = Golf::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 7)));
+        game.tableau[0].push(Face::Up(card!(S 8)));
+        assert!(game.is_valid(&Play::TableauWaste(Tableau::One)));
+    }
+
+    #[test]
+    fn valid_tableau_waste_pred() {
+        let mut game = Golf::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 7)));
+        game.tableau[0].push(Face::Up(card!(C 6)));
+        assert!(game.is_valid(&Play::TableauWaste(Tableau::One)));
+    }
+
+    #[test]
+    fn invalid_tableau_waste_empty_tableau() {
+        let mut game = Golf::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 7)));
+        assert!(!game.is_valid(&Play::TableauWaste(Tableau::One)));
+    }
+
+    #[test]
+    fn invalid_tableau_waste_empty_waste() {
+        let mut game = Golf::new(Rules::default());
+        game.tableau[0].push(Face::Up(card!(C 6)));
+        assert!(!game.is_valid(&Play::TableauWaste(Tableau::One)));
+    }
+
+    #[test]
+    fn invalid_tableau_waste_same_rank() {
+        let mut game = Golf::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 7)));
+        game.tableau[0].push(Face::Up(card!(C 7)));
+        assert!(!game.is_valid(&Play::TableauWaste(Tableau::One)));
+    }
+
+    #[test]
+    fn invalid_tableau_waste_kings_block() {
+        let mut game = Golf::new(Rules::default());
+        game.waste.push(Face::Up(card!(H K)));
+        game.tableau[0].push(Face::Up(card!(C Q)));
+        assert!(!game.is_valid(&Play::TableauWaste(Tableau::One)));
+    }
+
+    #[test]
+    fn valid_tableau_waste_kings_not_blocking() {
+        let mut game = Golf::new(Rules { wrap: false, kings_block: false });
+        game.waste.push(Face::Up(card!(H K)));
+        game.tableau[0].push(Face::Up(card!(C Q)));
+        assert!(game.is_valid(&Play::TableauWaste(Tableau::One)));
+    }
+
+    #[test]
+    fn invalid_tableau_waste_no_wrap() {
+        let mut game = Golf::new(Rules::default());
+        game.waste.push(Face::Up(card!(H A)));
+        game.tableau[0].push(Face::Up(card!(C K)));
+        assert!(!game.is_valid(&Play::TableauWaste(Tableau::One)));
+    }
+
+    #[test]
+    fn valid_tableau_waste_wrap_king_on_ace() {
+        let mut game = Golf::new(Rules { wrap: true, kings_block: true });
+        game.waste.push(Face::Up(card!(H A)));
+        game.tableau[0].push(Face::Up(card!(C K)));
+        assert!(game.is_valid(&Play::TableauWaste(Tableau::One)));
+    }
+
+    #[test]
+    fn valid_tableau_waste_wrap_ace_on_king() {
+        let mut game = Golf::new(Rules { wrap: true, kings_block: false });
+        game.waste.push(Face::Up(card!(H K)));
+        game.tableau[0].push(Face::Up(card!(C A)));
+        assert!(game.is_valid(&Play::TableauWaste(Tableau::One)));
+    }
+}
+
+mod play {
+    use card::Face;
+    use game::Game;
+    use game::golf::{Golf, Rules, Play, Tableau};
+
+    #[test]
+    fn deal() {
+        let mut game = Golf::new(Rules::default());
+        game.deal();
+        assert!(game.tableau.iter().all(|pile| pile.count() == 5));
+        assert!(game.tableau.iter().all(|pile| pile.into_iter().all(Face::is_up)));
+        assert_eq!(1, game.waste.count());
+        assert_eq!(16, game.stock.count());
+    }
+
+    #[test]
+    fn draw() {
+        let mut game = Golf::new(Rules::default());
+        let top = game.stock.top();
+        game.play(&Play::Draw);
+        assert_eq!(top.map(Face::flipped), game.waste.top());
+    }
+
+    #[test]
+    fn tableau_waste() {
+        let mut game = Golf::new(Rules::default());
+        game.deal();
+        let card = game.tableau[0].top();
+        game.play(&Play::TableauWaste(Tableau::One));
+        assert_eq!(card, game.waste.top());
+        assert_eq!(4, game.tableau[0].count());
+    }
+}
+
+mod score {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::golf::{Golf, Rules};
+
+    #[test]
+    fn cards_left() {
+        let mut game = Golf::new(Rules::default());
+        game.deal();
+        assert_eq!(35, game.score());
+    }
+
+    #[test]
+    fn cleared() {
+        let mut game = Golf::new(Rules::default());
+        game.stock = Pile::new();
+        game.stock.push(Face::Down(card!(H A)));
+        game.stock.push(Face::Down(card!(H 2)));
+        assert!(game.is_won());
+        assert_eq!(-2, game.score());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::golf::{Golf, Rules, Play, Tableau};
+
+    macro_rules! undo_test {
+        ($name:ident, $play:expr) => {
+            #[test]
+            fn $name() {
+                let mut before = Golf::new(Rules::default());
+                before.deal();
+                let mut after = before.clone();
+                after.play(&$play);
+                after.undo(&$play);
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    undo_test!(draw, Play::Draw);
+    undo_test!(tableau_waste, Play::TableauWaste(Tableau::One));
+}
@@ -1,5 +1,7 @@
 use std::mem;
 
+use rand::{self, XorShiftRng, SeedableRng};
+
 use card::{Rank, Card, Face, Set, Pile};
 use game::Game;
 use super::{Klondike, Draw, Play, Foundation, Tableau};
@@ -9,25 +11,12 @@ impl Game for Klondike {
     type Play = Play;
 
     fn new(draw: Draw) -> Self {
-        Klondike {
-            draw: draw,
-            stock: Set::new().map(Face::Down).collect(),
-            waste: Pile::new(),
-            foundations: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
-            tableau: [
-                Pile::new(),
-                Pile::new(),
-                Pile::new(),
-                Pile::new(),
-                Pile::new(),
-                Pile::new(),
-                Pile::new(),
-            ],
-        }
+        Klondike::with_seed(draw, rand::random())
     }
 
     fn deal(&mut self) {
-        self.stock.shuffle();
+        let mut rng = XorShiftRng::from_seed([self.seed, 0x9e37_79b9, 0x7f4a_7c15, 0xf39c_c060]);
+        self.stock.shuffle_with(&mut rng);
         for (i, pile) in self.tableau.iter_mut().enumerate() {
             self.stock.deal_to(pile, i + 1, false);
             pile.flip_top();
@@ -88,9 +77,14 @@ impl Game for Klondike {
 
     fn play(&mut self, play: &Self::Play) {
         match *play {
-            Play::Draw => match self.draw {
-                Draw::One => self.stock.deal_to(&mut self.waste, 1, true),
-                Draw::Three => self.stock.deal_to(&mut self.waste, 3, true),
+            Play::Draw => {
+                let count = match self.draw {
+                    Draw::One => 1,
+                    Draw::Three => 3,
+                };
+                let count = self.stock.count().min(count);
+                self.stock.deal_to(&mut self.waste, count, true);
+                self.draws.push(count);
             },
 
             Play::Redeal => {
@@ -138,9 +132,9 @@ impl Game for Klondike {
 
     fn undo(&mut self, play: &Play) {
         match *play {
-            Play::Draw => match self.draw {
-                Draw::One => self.waste.deal_to(&mut self.stock, 1, true),
-                Draw::Three => self.waste.deal_to(&mut self.stock, 3, true),
+            Play::Draw => {
+                let count = self.draws.pop().unwrap_or(0);
+                self.waste.deal_to(&mut self.stock, count, true);
             },
 
             Play::Redeal => {
@@ -148,7 +142,7 @@ impl Game for Klondike {
                 self.waste.flip();
             },
 
-            Play::Reveal(_) => self.play(&play),
+            Play::Reveal(_) => self.play(play),
 
             Play::WasteTableau(tableau) => {
                 self.tableau[tableau as usize].move_to(&mut self.waste, 1);
@@ -174,6 +168,57 @@ impl Game for Klondike {
 }
 
 impl Klondike {
+    /// Creates a game whose deal is determined by a seed.
+    pub fn with_seed(draw: Draw, seed: u32) -> Self {
+        Klondike {
+            draw: draw,
+            seed: seed,
+            stock: Set::new().map(Face::Down).collect(),
+            waste: Pile::new(),
+            draws: Vec::new(),
+            foundations: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
+            tableau: [
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+            ],
+        }
+    }
+
+    /// Returns the seed.
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    /// Returns the draw rule.
+    pub fn draw(&self) -> Draw {
+        self.draw
+    }
+
+    /// Returns the stock.
+    pub fn stock(&self) -> &Pile {
+        &self.stock
+    }
+
+    /// Returns the waste.
+    pub fn waste(&self) -> &Pile {
+        &self.waste
+    }
+
+    /// Returns a foundation.
+    pub fn foundation(&self, foundation: Foundation) -> &Pile {
+        &self.foundations[foundation as usize]
+    }
+
+    /// Returns a tableau pile.
+    pub fn tableau(&self, tableau: Tableau) -> &Pile {
+        &self.tableau[tableau as usize]
+    }
+
     fn is_valid_foundation(&self, foundation: Foundation, card: Card) -> bool {
         let foundation = &self.foundations[foundation as usize];
         if foundation.is_empty() {
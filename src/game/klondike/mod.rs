@@ -6,8 +6,10 @@ use card::Pile;
 #[derive(Debug, PartialEq, Eq)]
 pub struct Klondike {
     draw: Draw,
+    seed: u32,
     stock: Pile,
     waste: Pile,
+    draws: Vec<usize>,
     foundations: [Pile; 4],
     tableau: [Pile; 7],
 }
@@ -17,8 +19,10 @@ impl Clone for Klondike {
     fn clone(&self) -> Self {
         Klondike {
             draw: self.draw,
+            seed: self.seed,
             stock: self.stock.clone(),
             waste: self.waste.clone(),
+            draws: self.draws.clone(),
             foundations: [
                 self.foundations[0].clone(),
                 self.foundations[1].clone(),
@@ -74,6 +78,22 @@ pub enum Play {
     TableauTableau(Tableau, u8, Tableau),
 }
 
+/// Pile or card drawn by rendering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    /// Stock.
+    Stock,
+
+    /// Waste.
+    Waste,
+
+    /// Foundation.
+    Foundation(Foundation),
+
+    /// Tableau pile and depth of a card from the top, 0 for the top card or an empty pile.
+    Tableau(Tableau, u8),
+}
+
 /// Foundations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[allow(missing_docs)]
@@ -97,7 +117,26 @@ pub enum Tableau {
     Seven,
 }
 
+const FOUNDATIONS: [Foundation; 4] = [
+    Foundation::One,
+    Foundation::Two,
+    Foundation::Three,
+    Foundation::Four,
+];
+
+const TABLEAUX: [Tableau; 7] = [
+    Tableau::One,
+    Tableau::Two,
+    Tableau::Three,
+    Tableau::Four,
+    Tableau::Five,
+    Tableau::Six,
+    Tableau::Seven,
+];
+
 mod game;
+mod render;
+mod resolve;
 
 #[cfg(test)]
 mod tests;
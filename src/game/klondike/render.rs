@@ -0,0 +1,111 @@
+use std::fmt::{self, Display, Formatter};
+
+use card::Face;
+use ui::{Canvas, Width, Height, Render};
+use super::{Klondike, Draw, Target, FOUNDATIONS, TABLEAUX};
+
+impl Render for Klondike {
+    type Target = Target;
+
+    fn render_with_targets<C: Canvas>(
+        &self,
+        canvas: &mut C,
+        on_target: &mut dyn FnMut(Target),
+    ) {
+        canvas.push();
+
+        on_target(Target::Stock);
+        canvas.draw(self.stock.top());
+        canvas.right(Width::Card);
+        canvas.right(Width::Edge);
+
+        canvas.push();
+        on_target(Target::Waste);
+        let fan = match self.draw {
+            Draw::One => 1,
+            Draw::Three => 3,
+        };
+        let fan = fan.min(self.waste.count());
+        if fan == 0 {
+            canvas.draw(None);
+        }
+        for i in (1..fan + 1).rev() {
+            canvas.draw(self.waste.get_back(i));
+            canvas.right(Width::Border);
+        }
+        canvas.pop();
+
+        canvas.right(Width::Card);
+        canvas.right(Width::Edge);
+        canvas.right(Width::Card);
+        canvas.right(Width::Edge);
+        for (&foundation, pile) in FOUNDATIONS.iter().zip(&self.foundations) {
+            on_target(Target::Foundation(foundation));
+            canvas.draw(pile.top());
+            canvas.right(Width::Card);
+            canvas.right(Width::Edge);
+        }
+
+        canvas.pop();
+        canvas.push();
+
+        canvas.down(Height::Card);
+        canvas.down(Height::Edge);
+        for (&tableau, pile) in TABLEAUX.iter().zip(&self.tableau) {
+            canvas.push();
+            if pile.is_empty() {
+                on_target(Target::Tableau(tableau, 0));
+                canvas.draw(None);
+            }
+            for (i, face) in pile.vec.iter().enumerate() {
+                on_target(Target::Tableau(tableau, (pile.count() - 1 - i) as u8));
+                canvas.draw(Some(*face));
+                if face.is_up() {
+                    canvas.down(Height::Border);
+                } else {
+                    canvas.down(Height::Edge);
+                }
+            }
+            canvas.pop();
+            canvas.right(Width::Card);
+            canvas.right(Width::Edge);
+        }
+
+        canvas.pop();
+    }
+}
+
+fn cell(face: Option<Face>) -> String {
+    match face {
+        Some(face) => {
+            let [a, b] = face.ascii();
+            format!("[{}{}]", a, b)
+        },
+        None => String::from("[  ]"),
+    }
+}
+
+/// ASCII text dump, e.g. `[QH] [##]`: the stock, waste top and foundation tops on the first line,
+/// then the tableau piles in columns.
+impl Display for Klondike {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut line = format!("{} {}     ", cell(self.stock.top()), cell(self.waste.top()));
+        for foundation in &self.foundations {
+            line += &format!(" {}", cell(foundation.top()));
+        }
+        writeln!(f, "{}", line)?;
+
+        let height = self.tableau.iter().map(|pile| pile.count()).max().unwrap_or(0).max(1);
+        for row in 0..height {
+            let cells: Vec<String> = self.tableau.iter().map(|pile| {
+                match pile.get(row) {
+                    Some(face) => cell(Some(face)),
+                    None if row == 0 => cell(None),
+                    None => String::from("    "),
+                }
+            }).collect();
+            writeln!(f, "{}", cells.join(" ").trim_end())?;
+        }
+        Ok(())
+    }
+}
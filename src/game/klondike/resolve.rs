@@ -0,0 +1,63 @@
+use game::Game;
+use super::{Klondike, Play, Target, FOUNDATIONS, TABLEAUX};
+
+impl Klondike {
+    /// Returns the valid play moving the card at the source target, and any cards above it, to
+    /// the destination target. The depth of a destination tableau target is ignored.
+    pub fn resolve(&self, src: Target, dest: Target) -> Option<Play> {
+        let play = match (src, dest) {
+            (Target::Waste, Target::Tableau(tableau, _)) => Play::WasteTableau(tableau),
+            (Target::Waste, Target::Foundation(foundation)) => Play::WasteFoundation(foundation),
+            (Target::Foundation(foundation), Target::Tableau(tableau, _)) => {
+                Play::FoundationTableau(foundation, tableau)
+            },
+            (Target::Tableau(tableau, 0), Target::Foundation(foundation)) => {
+                Play::TableauFoundation(tableau, foundation)
+            },
+            (Target::Tableau(src, depth), Target::Tableau(dest, _)) if src != dest => {
+                Play::TableauTableau(src, depth.checked_add(1)?, dest)
+            },
+            _ => return None,
+        };
+        if self.is_valid(&play) {
+            Some(play)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the best valid play for a single click on a target: drawing or redealing the
+    /// stock, revealing a tableau card, or moving the card to a foundation, then to a tableau
+    /// pile.
+    pub fn smart_move(&self, target: Target) -> Option<Play> {
+        match target {
+            Target::Stock => {
+                return [Play::Draw, Play::Redeal].iter()
+                    .find(|play| self.is_valid(play))
+                    .cloned();
+            },
+            Target::Tableau(tableau, 0) if self.is_valid(&Play::Reveal(tableau)) => {
+                return Some(Play::Reveal(tableau));
+            },
+            _ => {},
+        }
+
+        FOUNDATIONS.iter()
+            .map(|&foundation| Target::Foundation(foundation))
+            .chain(TABLEAUX.iter().map(|&tableau| Target::Tableau(tableau, 0)))
+            .filter(|&dest| !self.is_pointless(target, dest))
+            .filter_map(|dest| self.resolve(target, dest))
+            .next()
+    }
+
+    /// Returns true if moving the target would only move a whole tableau pile to an empty one.
+    fn is_pointless(&self, src: Target, dest: Target) -> bool {
+        match (src, dest) {
+            (Target::Tableau(src, depth), Target::Tableau(dest, _)) => {
+                self.tableau[src as usize].count() == depth as usize + 1
+                    && self.tableau[dest as usize].is_empty()
+            },
+            _ => false,
+        }
+    }
+}
@@ -446,9 +446,22 @@ mod play {
         assert_eq!(a, game.tableau[0].get_back(2));
         assert_eq!(b, game.tableau[0].get_back(1));
     }
+
+    #[test]
+    fn deal_seeded() {
+        let mut a = Klondike::with_seed(Draw::One, 42);
+        let mut b = Klondike::with_seed(Draw::One, 42);
+        let mut c = Klondike::with_seed(Draw::One, 43);
+        a.deal();
+        b.deal();
+        c.deal();
+        assert_eq!(a, b);
+        assert!(a.tableau != c.tableau);
+    }
 }
 
 mod undo {
+    use card::{Face, Pile};
     use game::Game;
     use game::klondike::{Klondike, Draw, Play, Foundation, Tableau};
 
@@ -488,6 +501,19 @@ mod undo {
         assert_eq!(before, after);
     }
 
+    #[test]
+    fn draw_three_short() {
+        let mut before = Klondike::new(Draw::Three);
+        before.stock = Pile::new();
+        before.stock.push(Face::Down(card!(H 4)));
+        before.waste.push(Face::Up(card!(H 2)));
+        before.waste.push(Face::Up(card!(H 3)));
+        let mut after = before.clone();
+        after.play(&Play::Draw);
+        after.undo(&Play::Draw);
+        assert_eq!(before, after);
+    }
+
     #[test]
     fn redeal() {
         let mut before = Klondike::new(Draw::Three);
@@ -508,3 +534,410 @@ mod undo {
     undo_test!(foundation_tableau, Play::FoundationTableau(Foundation::One, Tableau::One));
     undo_test!(tableau_tableau, Play::TableauTableau(Tableau::Three, 2, Tableau::One));
 }
+
+mod render {
+    use card::Face;
+    use game::Game;
+    use game::klondike::{Klondike, Draw};
+    use ui::Render;
+    use ui::record::Record;
+
+    fn record(game: &Klondike) -> Record {
+        let mut record = Record::default();
+        game.render(&mut record);
+        record
+    }
+
+    #[test]
+    fn empty() {
+        let mut game = Klondike::new(Draw::One);
+        game.stock.vec.clear();
+        let record = record(&game);
+        assert_eq!(record.draws, vec![
+            (0, 0, None),
+            (8, 0, None),
+            (24, 0, None),
+            (32, 0, None),
+            (40, 0, None),
+            (48, 0, None),
+            (0, 6, None),
+            (8, 6, None),
+            (16, 6, None),
+            (24, 6, None),
+            (32, 6, None),
+            (40, 6, None),
+            (48, 6, None),
+        ]);
+    }
+
+    #[test]
+    fn position_unchanged() {
+        let mut game = Klondike::new(Draw::Three);
+        game.deal();
+        assert_eq!(record(&game).position(), (0, 0));
+    }
+
+    #[test]
+    fn stock_top() {
+        let game = Klondike::new(Draw::One);
+        assert_eq!(record(&game).draws[0], (0, 0, game.stock.top()));
+    }
+
+    #[test]
+    fn waste_draw_one() {
+        let mut game = Klondike::new(Draw::One);
+        game.waste.push(Face::Up(card!(H A)));
+        game.waste.push(Face::Up(card!(H 2)));
+        let record = record(&game);
+        assert_eq!(record.draws[1], (8, 0, Some(Face::Up(card!(H 2)))));
+        assert_eq!(record.draws[2], (24, 0, None));
+    }
+
+    #[test]
+    fn waste_draw_three_fanned() {
+        let mut game = Klondike::new(Draw::Three);
+        game.waste.push(Face::Up(card!(H A)));
+        game.waste.push(Face::Up(card!(H 2)));
+        game.waste.push(Face::Up(card!(H 3)));
+        game.waste.push(Face::Up(card!(H 4)));
+        let record = record(&game);
+        assert_eq!(&record.draws[1..4], &[
+            (8, 0, Some(Face::Up(card!(H 2)))),
+            (11, 0, Some(Face::Up(card!(H 3)))),
+            (14, 0, Some(Face::Up(card!(H 4)))),
+        ]);
+    }
+
+    #[test]
+    fn waste_draw_three_short() {
+        let mut game = Klondike::new(Draw::Three);
+        game.waste.push(Face::Up(card!(H A)));
+        let record = record(&game);
+        assert_eq!(record.draws[1], (8, 0, Some(Face::Up(card!(H A)))));
+        assert_eq!(record.draws[2], (24, 0, None));
+    }
+
+    #[test]
+    fn foundation_top() {
+        let mut game = Klondike::new(Draw::One);
+        game.foundations[2].push(Face::Up(card!(C A)));
+        game.foundations[2].push(Face::Up(card!(C 2)));
+        assert_eq!(record(&game).draws[4], (40, 0, Some(Face::Up(card!(C 2)))));
+    }
+
+    #[test]
+    fn tableau_cascade() {
+        let mut game = Klondike::new(Draw::One);
+        game.tableau[1].push(Face::Down(card!(S 9)));
+        game.tableau[1].push(Face::Down(card!(S 8)));
+        game.tableau[1].push(Face::Up(card!(H K)));
+        game.tableau[1].push(Face::Up(card!(C Q)));
+        let record = record(&game);
+        assert_eq!(&record.draws[7..11], &[
+            (8, 6, Some(Face::Down(card!(S 9)))),
+            (8, 7, Some(Face::Down(card!(S 8)))),
+            (8, 8, Some(Face::Up(card!(H K)))),
+            (8, 10, Some(Face::Up(card!(C Q)))),
+        ]);
+    }
+}
+
+mod hit {
+    use card::Face;
+    use game::Game;
+    use game::klondike::{Klondike, Draw, Target, Foundation, Tableau};
+    use ui::{Width, Height};
+    use ui::hit::Hits;
+    use ui::terminal;
+
+    fn columns(width: Width) -> u32 {
+        terminal::columns(width) as u32
+    }
+
+    fn rows(height: Height) -> u32 {
+        terminal::rows(height) as u32
+    }
+
+    fn hits(game: &Klondike) -> Hits<Target> {
+        Hits::of(game, columns, rows)
+    }
+
+    #[test]
+    fn stock() {
+        let game = Klondike::new(Draw::One);
+        let hits = hits(&game);
+        assert_eq!(hits.hit(0, 0), Some(Target::Stock));
+        assert_eq!(hits.hit(6, 4), Some(Target::Stock));
+    }
+
+    #[test]
+    fn gap() {
+        let game = Klondike::new(Draw::One);
+        let hits = hits(&game);
+        assert_eq!(hits.hit(7, 0), None);
+        assert_eq!(hits.hit(0, 5), None);
+    }
+
+    #[test]
+    fn waste_fanned() {
+        let mut game = Klondike::new(Draw::Three);
+        game.stock.deal_to(&mut game.waste, 3, true);
+        let hits = hits(&game);
+        assert_eq!(hits.hit(8, 0), Some(Target::Waste));
+        assert_eq!(hits.hit(20, 0), Some(Target::Waste));
+        assert_eq!(hits.hit(21, 0), None);
+    }
+
+    #[test]
+    fn foundation() {
+        let game = Klondike::new(Draw::One);
+        let hits = hits(&game);
+        assert_eq!(hits.hit(24, 0), Some(Target::Foundation(Foundation::One)));
+        assert_eq!(hits.hit(54, 4), Some(Target::Foundation(Foundation::Four)));
+    }
+
+    #[test]
+    fn tableau_empty() {
+        let game = Klondike::new(Draw::One);
+        let hits = hits(&game);
+        assert_eq!(hits.hit(16, 6), Some(Target::Tableau(Tableau::Three, 0)));
+    }
+
+    #[test]
+    fn tableau_depth() {
+        let mut game = Klondike::new(Draw::One);
+        game.deal();
+        let hits = hits(&game);
+        assert_eq!(hits.hit(48, 6), Some(Target::Tableau(Tableau::Seven, 6)));
+        assert_eq!(hits.hit(48, 11), Some(Target::Tableau(Tableau::Seven, 1)));
+        assert_eq!(hits.hit(48, 12), Some(Target::Tableau(Tableau::Seven, 0)));
+        assert_eq!(hits.hit(48, 16), Some(Target::Tableau(Tableau::Seven, 0)));
+        assert_eq!(hits.hit(48, 17), None);
+    }
+
+    #[test]
+    fn tableau_depth_face_up() {
+        let mut game = Klondike::new(Draw::One);
+        game.tableau[1].push(Face::Up(card!(H K)));
+        game.tableau[1].push(Face::Up(card!(C Q)));
+        let hits = hits(&game);
+        assert_eq!(hits.hit(8, 7), Some(Target::Tableau(Tableau::Two, 1)));
+        assert_eq!(hits.hit(8, 8), Some(Target::Tableau(Tableau::Two, 0)));
+    }
+}
+
+mod resolve {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::klondike::{Klondike, Draw, Play, Target, Foundation, Tableau};
+
+    fn game() -> Klondike {
+        let mut game = Klondike::new(Draw::One);
+        game.stock = Pile::new();
+        game
+    }
+
+    #[test]
+    fn waste_tableau() {
+        let mut game = game();
+        game.waste.push(Face::Up(card!(C K)));
+        assert_eq!(
+            game.resolve(Target::Waste, Target::Tableau(Tableau::Two, 0)),
+            Some(Play::WasteTableau(Tableau::Two))
+        );
+    }
+
+    #[test]
+    fn waste_foundation() {
+        let mut game = game();
+        game.waste.push(Face::Up(card!(C A)));
+        assert_eq!(
+            game.resolve(Target::Waste, Target::Foundation(Foundation::Three)),
+            Some(Play::WasteFoundation(Foundation::Three))
+        );
+    }
+
+    #[test]
+    fn foundation_tableau() {
+        let mut game = game();
+        game.foundations[0].push(Face::Up(card!(H A)));
+        game.foundations[0].push(Face::Up(card!(H 2)));
+        game.tableau[3].push(Face::Up(card!(S 3)));
+        assert_eq!(
+            game.resolve(Target::Foundation(Foundation::One), Target::Tableau(Tableau::Four, 0)),
+            Some(Play::FoundationTableau(Foundation::One, Tableau::Four))
+        );
+    }
+
+    #[test]
+    fn tableau_foundation() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(D A)));
+        assert_eq!(
+            game.resolve(Target::Tableau(Tableau::One, 0), Target::Foundation(Foundation::One)),
+            Some(Play::TableauFoundation(Tableau::One, Foundation::One))
+        );
+    }
+
+    #[test]
+    fn tableau_foundation_deep() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(D A)));
+        game.tableau[0].push(Face::Up(card!(S K)));
+        assert_eq!(
+            game.resolve(Target::Tableau(Tableau::One, 1), Target::Foundation(Foundation::One)),
+            None
+        );
+    }
+
+    #[test]
+    fn tableau_tableau_depth() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(H K)));
+        game.tableau[0].push(Face::Up(card!(C Q)));
+        game.tableau[0].push(Face::Up(card!(D J)));
+        game.tableau[1].push(Face::Up(card!(S K)));
+        game.tableau[1].push(Face::Up(card!(H Q)));
+        assert_eq!(
+            game.resolve(Target::Tableau(Tableau::Two, 0), Target::Tableau(Tableau::Three, 0)),
+            None
+        );
+        assert_eq!(
+            game.resolve(Target::Tableau(Tableau::Two, 1), Target::Tableau(Tableau::Three, 0)),
+            Some(Play::TableauTableau(Tableau::Two, 2, Tableau::Three))
+        );
+    }
+
+    #[test]
+    fn tableau_tableau_depth_overflow() {
+        let game = game();
+        assert_eq!(
+            game.resolve(Target::Tableau(Tableau::One, 255), Target::Tableau(Tableau::Two, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn tableau_same() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(H K)));
+        assert_eq!(
+            game.resolve(Target::Tableau(Tableau::One, 0), Target::Tableau(Tableau::One, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let mut game = game();
+        game.waste.push(Face::Up(card!(C 5)));
+        assert_eq!(game.resolve(Target::Waste, Target::Tableau(Tableau::One, 0)), None);
+        assert_eq!(game.resolve(Target::Waste, Target::Stock), None);
+    }
+
+    #[test]
+    fn smart_draw() {
+        let game = Klondike::new(Draw::One);
+        assert_eq!(game.smart_move(Target::Stock), Some(Play::Draw));
+    }
+
+    #[test]
+    fn smart_redeal() {
+        let mut game = game();
+        game.waste.push(Face::Up(card!(C 5)));
+        assert_eq!(game.smart_move(Target::Stock), Some(Play::Redeal));
+    }
+
+    #[test]
+    fn smart_reveal() {
+        let mut game = game();
+        game.tableau[2].push(Face::Down(card!(C 5)));
+        assert_eq!(
+            game.smart_move(Target::Tableau(Tableau::Three, 0)),
+            Some(Play::Reveal(Tableau::Three))
+        );
+    }
+
+    #[test]
+    fn smart_foundation_first() {
+        let mut game = game();
+        game.foundations[0].push(Face::Up(card!(H A)));
+        game.tableau[0].push(Face::Up(card!(S 3)));
+        game.waste.push(Face::Up(card!(H 2)));
+        assert_eq!(
+            game.smart_move(Target::Waste),
+            Some(Play::WasteFoundation(Foundation::One))
+        );
+    }
+
+    #[test]
+    fn smart_tableau() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(H 9)));
+        game.tableau[4].push(Face::Up(card!(S 10)));
+        assert_eq!(
+            game.smart_move(Target::Tableau(Tableau::One, 0)),
+            Some(Play::TableauTableau(Tableau::One, 1, Tableau::Five))
+        );
+    }
+
+    #[test]
+    fn smart_king_whole_pile() {
+        let mut game = game();
+        game.tableau[0].push(Face::Up(card!(H K)));
+        assert_eq!(game.smart_move(Target::Tableau(Tableau::One, 0)), None);
+    }
+
+    #[test]
+    fn smart_king_uncovers() {
+        let mut game = game();
+        game.tableau[0].push(Face::Down(card!(C 2)));
+        game.tableau[0].push(Face::Up(card!(H K)));
+        assert_eq!(
+            game.smart_move(Target::Tableau(Tableau::One, 0)),
+            Some(Play::TableauTableau(Tableau::One, 1, Tableau::Two))
+        );
+    }
+}
+
+mod dump {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::klondike::{Klondike, Draw};
+
+    #[test]
+    fn empty() {
+        let mut game = Klondike::new(Draw::One);
+        game.stock = Pile::new();
+        assert_eq!(
+            game.to_string(),
+            "[  ] [  ]      [  ] [  ] [  ] [  ]\n\
+             [  ] [  ] [  ] [  ] [  ] [  ] [  ]\n"
+        );
+    }
+
+    #[test]
+    fn position() {
+        let mut game = Klondike::new(Draw::One);
+        game.waste.push(Face::Up(card!(H Q)));
+        game.foundations[1].push(Face::Up(card!(C A)));
+        game.tableau[1].push(Face::Down(card!(S 9)));
+        game.tableau[1].push(Face::Up(card!(D 10)));
+        game.tableau[3].push(Face::Up(card!(S K)));
+        assert_eq!(
+            game.to_string(),
+            "[##] [QH]      [  ] [AC] [  ] [  ]\n\
+             [  ] [##] [  ] [KS] [  ] [  ] [  ]\n\
+             \x20    [TD]\n"
+        );
+    }
+
+    #[test]
+    fn dealt() {
+        let mut game = Klondike::new(Draw::One);
+        game.deal();
+        let dump = game.to_string();
+        assert_eq!(dump.lines().count(), 8);
+        assert!(dump.lines().last().unwrap().starts_with("                              ["));
+    }
+}
@@ -27,4 +27,24 @@ pub trait Game {
     fn undo(&mut self, play: &Self::Play);
 }
 
+pub mod accordion;
+pub mod aces_up;
+pub mod bakers_dozen;
+pub mod beleaguered_castle;
+pub mod belle_lucie;
+pub mod bristol;
+pub mod calculation;
+pub mod canfield;
+pub mod clock;
+pub mod forty_thieves;
+pub mod freecell;
+pub mod gaps;
+pub mod golf;
 pub mod klondike;
+pub mod osmosis;
+pub mod pyramid;
+pub mod scorpion;
+pub mod tripeaks;
+
+mod build;
+mod open;
@@ -0,0 +1,123 @@
+//! Open tableau shared by games dealt entirely face-up.
+
+use card::{Rank, Card, Pile};
+
+/// Tableau of face-up columns built down regardless of suit, with four foundations built up in
+/// suit from ace, moving one card at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenTableau {
+    pub foundations: [Pile; 4],
+    pub tableau: Vec<Pile>,
+    fill_empty: bool,
+}
+
+/// Open tableau play.
+///
+/// Foundations and tableaux are numbered from zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Move top card of tableau to foundation.
+    TableauFoundation(u8, u8),
+
+    /// Move top card of tableau to tableau.
+    TableauTableau(u8, u8),
+}
+
+impl OpenTableau {
+    /// Creates an empty open tableau, optionally allowing any card to fill an empty column.
+    pub fn new(columns: usize, fill_empty: bool) -> Self {
+        OpenTableau {
+            foundations: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
+            tableau: vec![Pile::new(); columns],
+            fill_empty: fill_empty,
+        }
+    }
+
+    /// Returns true if every card is on the foundations.
+    pub fn is_won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.count() == 13)
+    }
+
+    /// Determines if the play is currently valid.
+    pub fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::TableauFoundation(tableau, foundation) => {
+                self.tableau.get(tableau as usize).and_then(Pile::top).map_or(false, |face| {
+                    self.is_valid_foundation(foundation, face.card())
+                })
+            },
+
+            Play::TableauTableau(src, dest) => {
+                src != dest && self.tableau.get(src as usize).and_then(Pile::top).map_or(false, |face| {
+                    self.is_valid_tableau(dest, face.card())
+                })
+            },
+        }
+    }
+
+    /// Performs a play.
+    pub fn play(&mut self, play: &Play) {
+        match *play {
+            Play::TableauFoundation(tableau, foundation) => {
+                self.tableau[tableau as usize].move_to(
+                    &mut self.foundations[foundation as usize],
+                    1,
+                );
+            },
+
+            Play::TableauTableau(src, dest) => {
+                if src < dest {
+                    let (left, right) = self.tableau.split_at_mut(dest as usize);
+                    left[src as usize].move_to(&mut right[0], 1);
+                } else {
+                    let (left, right) = self.tableau.split_at_mut(src as usize);
+                    right[0].move_to(&mut left[dest as usize], 1);
+                }
+            },
+        }
+    }
+
+    /// Undoes a play.
+    pub fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::TableauFoundation(tableau, foundation) => {
+                self.foundations[foundation as usize].move_to(
+                    &mut self.tableau[tableau as usize],
+                    1,
+                );
+            },
+
+            Play::TableauTableau(src, dest) => {
+                self.play(&Play::TableauTableau(dest, src));
+            },
+        }
+    }
+
+    /// Returns true if a card may be moved to a foundation.
+    pub fn is_valid_foundation(&self, foundation: u8, card: Card) -> bool {
+        let foundation = match self.foundations.get(foundation as usize) {
+            Some(foundation) => foundation,
+            None => return false,
+        };
+        if foundation.is_empty() {
+            card.rank == Rank::Ace
+        } else {
+            let top = foundation.top().unwrap().card();
+            card.suit == top.suit && card.rank.pred() == Some(top.rank)
+        }
+    }
+
+    /// Returns true if a card may be moved to a tableau.
+    pub fn is_valid_tableau(&self, tableau: u8, card: Card) -> bool {
+        match self.tableau.get(tableau as usize).map(Pile::top) {
+            None => false,
+            Some(None) => self.fill_empty,
+            Some(Some(top)) => card.rank.succ() == Some(top.card().rank),
+        }
+    }
+}
+
+/// Moves the kings of a pile to its bottom, where they cannot bury other cards.
+pub fn kings_to_bottom(pile: &mut Pile) {
+    pile.vec.sort_by_key(|face| face.card().rank != Rank::King);
+}
@@ -0,0 +1,130 @@
+use std::mem;
+
+use card::{Rank, Card, Face, Set, Pile};
+use game::Game;
+use super::{Osmosis, Play, Foundation};
+
+impl Game for Osmosis {
+    type Rules = ();
+    type Play = Play;
+
+    fn new(_: ()) -> Self {
+        Osmosis {
+            base: Rank::Ace,
+            stock: Set::new().map(Face::Down).collect(),
+            waste: Pile::new(),
+            draws: Vec::new(),
+            reserve: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
+            foundations: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        for pile in &mut self.reserve {
+            self.stock.deal_to(pile, 4, false);
+            pile.flip_top();
+        }
+        self.stock.deal_to(&mut self.foundations[0], 1, true);
+        self.base = self.foundations[0].top().unwrap().card().rank;
+    }
+
+    fn is_won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.count() == 13)
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::Draw => {
+                !self.stock.is_empty()
+            },
+
+            Play::Redeal => {
+                self.stock.is_empty() && !self.waste.is_empty()
+            },
+
+            Play::WasteFoundation(foundation) => {
+                self.waste.top().map_or(false, |face| {
+                    self.is_valid_foundation(foundation, face.card())
+                })
+            },
+
+            Play::ReserveFoundation(reserve, foundation) => {
+                self.reserve[reserve as usize].top().map_or(false, |face| {
+                    self.is_valid_foundation(foundation, face.card())
+                })
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::Draw => {
+                let count = self.stock.count().min(3);
+                self.stock.deal_to(&mut self.waste, count, true);
+                self.draws.push(count);
+            },
+
+            Play::Redeal => {
+                mem::swap(&mut self.waste, &mut self.stock);
+                self.stock.flip();
+            },
+
+            Play::WasteFoundation(foundation) => {
+                self.waste.move_to(&mut self.foundations[foundation as usize], 1);
+            },
+
+            Play::ReserveFoundation(reserve, foundation) => {
+                let reserve = &mut self.reserve[reserve as usize];
+                reserve.move_to(&mut self.foundations[foundation as usize], 1);
+                reserve.flip_top();
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::Draw => {
+                let count = self.draws.pop().unwrap_or(0);
+                self.waste.deal_to(&mut self.stock, count, true);
+            },
+
+            Play::Redeal => {
+                mem::swap(&mut self.stock, &mut self.waste);
+                self.waste.flip();
+            },
+
+            Play::WasteFoundation(foundation) => {
+                self.foundations[foundation as usize].move_to(&mut self.waste, 1);
+            },
+
+            Play::ReserveFoundation(reserve, foundation) => {
+                let reserve = &mut self.reserve[reserve as usize];
+                reserve.flip_top();
+                self.foundations[foundation as usize].move_to(reserve, 1);
+            },
+        }
+    }
+}
+
+impl Osmosis {
+    /// Returns the rank every foundation starts with, chosen by the first card dealt to them.
+    pub fn base(&self) -> Rank {
+        self.base
+    }
+
+    /// Returns true if a card may be moved to a foundation.
+    ///
+    /// Each foundation holds a single suit in any order of rank, but a card may only be added
+    /// once a card of the same rank is in the foundation above.
+    fn is_valid_foundation(&self, foundation: Foundation, card: Card) -> bool {
+        let index = foundation as usize;
+        let in_row_above = index == 0 || self.foundations[index - 1].into_iter().any(|face| {
+            face.card().rank == card.rank
+        });
+        match self.foundations[index].get(0) {
+            None => card.rank == self.base && in_row_above,
+            Some(first) => card.suit == first.card().suit && in_row_above,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Osmosis solitaire, also known as Treasure Trove.
+
+use card::{Rank, Pile};
+
+/// Osmosis solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Osmosis {
+    base: Rank,
+    stock: Pile,
+    waste: Pile,
+    draws: Vec<usize>,
+    reserve: [Pile; 4],
+    foundations: [Pile; 4],
+}
+
+/// Osmosis play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Draw three cards from stock into waste.
+    Draw,
+
+    /// Move waste back to stock.
+    Redeal,
+
+    /// Move top card of waste to foundation.
+    WasteFoundation(Foundation),
+
+    /// Move top card of reserve to foundation.
+    ReserveFoundation(Reserve, Foundation),
+}
+
+/// Foundations, as rows from top to bottom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Foundation {
+    One,
+    Two,
+    Three,
+    Four,
+}
+
+/// Reserve piles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Reserve {
+    One,
+    Two,
+    Three,
+    Four,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,161 @@
+mod is_valid {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::osmosis::{Osmosis, Play, Foundation, Reserve};
+
+    fn game() -> Osmosis {
+        let mut game = Osmosis::new(());
+        game.base = rank!(5);
+        game.foundations[0].push(Face::Up(card!(H 5)));
+        game
+    }
+
+    #[test]
+    fn valid_draw_full_stock() {
+        let game = Osmosis::new(());
+        assert!(game.is_valid(&Play::Draw));
+    }
+
+    #[test]
+    fn valid_redeal_empty_stock() {
+        let mut game = Osmosis::new(());
+        game.stock.deal_to(&mut game.waste, 52, true);
+        assert!(game.is_valid(&Play::Redeal));
+    }
+
+    #[test]
+    fn invalid_redeal_empty_waste() {
+        let mut game = Osmosis::new(());
+        game.stock = Pile::new();
+        assert!(!game.is_valid(&Play::Redeal));
+    }
+
+    #[test]
+    fn valid_waste_foundation_first_row_suit() {
+        let mut game = game();
+        game.waste.push(Face::Up(card!(H J)));
+        assert!(game.is_valid(&Play::WasteFoundation(Foundation::One)));
+    }
+
+    #[test]
+    fn invalid_waste_foundation_first_row_non_suit() {
+        let mut game = game();
+        game.waste.push(Face::Up(card!(C J)));
+        assert!(!game.is_valid(&Play::WasteFoundation(Foundation::One)));
+    }
+
+    #[test]
+    fn valid_waste_foundation_start_base() {
+        let mut game = game();
+        game.waste.push(Face::Up(card!(C 5)));
+        assert!(game.is_valid(&Play::WasteFoundation(Foundation::Two)));
+    }
+
+    #[test]
+    fn invalid_waste_foundation_start_non_base() {
+        let mut game = game();
+        game.waste.push(Face::Up(card!(C 6)));
+        assert!(!game.is_valid(&Play::WasteFoundation(Foundation::Two)));
+    }
+
+    #[test]
+    fn invalid_waste_foundation_start_skipping_row() {
+        let mut game = game();
+        game.waste.push(Face::Up(card!(C 5)));
+        assert!(!game.is_valid(&Play::WasteFoundation(Foundation::Three)));
+    }
+
+    #[test]
+    fn valid_waste_foundation_rank_above() {
+        let mut game = game();
+        game.foundations[0].push(Face::Up(card!(H 9)));
+        game.foundations[1].push(Face::Up(card!(C 5)));
+        game.waste.push(Face::Up(card!(C 9)));
+        assert!(game.is_valid(&Play::WasteFoundation(Foundation::Two)));
+    }
+
+    #[test]
+    fn invalid_waste_foundation_rank_not_above() {
+        let mut game = game();
+        game.foundations[1].push(Face::Up(card!(C 5)));
+        game.waste.push(Face::Up(card!(C 9)));
+        assert!(!game.is_valid(&Play::WasteFoundation(Foundation::Two)));
+    }
+
+    #[test]
+    fn valid_reserve_foundation() {
+        let mut game = game();
+        game.reserve[2].push(Face::Up(card!(H 2)));
+        assert!(game.is_valid(&Play::ReserveFoundation(Reserve::Three, Foundation::One)));
+    }
+}
+
+mod play {
+    use card::Face;
+    use game::Game;
+    use game::osmosis::{Osmosis, Play, Foundation, Reserve};
+
+    #[test]
+    fn deal() {
+        let mut game = Osmosis::new(());
+        game.deal();
+        assert!(game.reserve.iter().all(|pile| {
+            pile.count() == 4 && pile.top().unwrap().is_up() && pile.get(2).unwrap().is_down()
+        }));
+        assert_eq!(Some(game.base()), game.foundations[0].top().map(|face| face.card().rank));
+        assert_eq!(35, game.stock.count());
+    }
+
+    #[test]
+    fn draw() {
+        let mut game = Osmosis::new(());
+        game.play(&Play::Draw);
+        assert_eq!(3, game.waste.count());
+    }
+
+    #[test]
+    fn reserve_foundation_reveals() {
+        let mut game = Osmosis::new(());
+        game.reserve[0].push(Face::Down(card!(S 2)));
+        game.reserve[0].push(Face::Up(card!(S 3)));
+        game.play(&Play::ReserveFoundation(Reserve::One, Foundation::One));
+        assert_eq!(Some(Face::Up(card!(S 2))), game.reserve[0].top());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::osmosis::{Osmosis, Play, Foundation, Reserve};
+
+    macro_rules! undo_test {
+        ($name:ident, $play:expr) => {
+            #[test]
+            fn $name() {
+                let mut before = Osmosis::new(());
+                before.deal();
+                before.play(&Play::Draw);
+                let mut after = before.clone();
+                after.play(&$play);
+                after.undo(&$play);
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    #[test]
+    fn redeal() {
+        let mut before = Osmosis::new(());
+        before.deal();
+        while !before.stock.is_empty() {
+            before.play(&Play::Draw);
+        }
+        let mut after = before.clone();
+        after.play(&Play::Redeal);
+        after.undo(&Play::Redeal);
+        assert_eq!(before, after);
+    }
+
+    undo_test!(draw, Play::Draw);
+    undo_test!(waste_foundation, Play::WasteFoundation(Foundation::Two));
+    undo_test!(reserve_foundation, Play::ReserveFoundation(Reserve::Four, Foundation::One));
+}
@@ -0,0 +1,166 @@
+use std::mem;
+
+use card::{Rank, Card, Face, Set, Pile};
+use game::Game;
+use super::{Pyramid, Rules, Play, Position};
+
+impl Game for Pyramid {
+    type Rules = Rules;
+    type Play = Play;
+
+    fn new(rules: Rules) -> Self {
+        Pyramid {
+            rules: rules,
+            redeals: 0,
+            stock: Set::new().map(Face::Down).collect(),
+            waste: Pile::new(),
+            foundation: Pile::new(),
+            pyramid: [None; 28],
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        for slot in &mut self.pyramid {
+            *slot = self.stock.pop().as_ref().map(Face::card);
+        }
+    }
+
+    fn is_won(&self) -> bool {
+        self.pyramid.iter().all(Option::is_none)
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::Draw => {
+                !self.stock.is_empty()
+            },
+
+            Play::Redeal => {
+                self.stock.is_empty()
+                    && !self.waste.is_empty()
+                    && self.redeals < self.rules.redeals
+            },
+
+            Play::Pair(a, b) => {
+                if a == b {
+                    return false;
+                }
+                match (self.available(a), self.available(b)) {
+                    (Some(a), Some(b)) => a.rank as u8 + b.rank as u8 == 13,
+                    _ => false,
+                }
+            },
+
+            Play::King(position) => {
+                self.available(position).map_or(false, |card| card.rank == Rank::King)
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::Draw => {
+                self.stock.deal_to(&mut self.waste, 1, true);
+            },
+
+            Play::Redeal => {
+                mem::swap(&mut self.waste, &mut self.stock);
+                self.stock.flip();
+                self.redeals += 1;
+            },
+
+            Play::Pair(a, b) => {
+                self.remove(a);
+                self.remove(b);
+            },
+
+            Play::King(position) => {
+                self.remove(position);
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::Draw => {
+                self.waste.deal_to(&mut self.stock, 1, true);
+            },
+
+            Play::Redeal => {
+                mem::swap(&mut self.stock, &mut self.waste);
+                self.waste.flip();
+                self.redeals -= 1;
+            },
+
+            Play::Pair(a, b) => {
+                self.restore(b);
+                self.restore(a);
+            },
+
+            Play::King(position) => {
+                self.restore(position);
+            },
+        }
+    }
+}
+
+impl Pyramid {
+    /// Returns the card at a position if it is available to be removed.
+    ///
+    /// A pyramid card is available once both cards covering it have been removed.
+    pub fn available(&self, position: Position) -> Option<Card> {
+        match position {
+            Position::Pyramid(index) => {
+                let index = index as usize;
+                if index >= self.pyramid.len() {
+                    return None;
+                }
+                let covered = covering(index).map_or(false, |(left, right)| {
+                    self.pyramid[left].is_some() || self.pyramid[right].is_some()
+                });
+                if covered { None } else { self.pyramid[index] }
+            },
+            Position::Stock => self.stock.top().as_ref().map(Face::card),
+            Position::Waste => self.waste.top().as_ref().map(Face::card),
+        }
+    }
+
+    fn remove(&mut self, position: Position) {
+        let card = match position {
+            Position::Pyramid(index) => self.pyramid[index as usize].take(),
+            Position::Stock => self.stock.pop().as_ref().map(Face::card),
+            Position::Waste => self.waste.pop().as_ref().map(Face::card),
+        };
+        if let Some(card) = card {
+            self.foundation.push(Face::Up(card));
+        }
+    }
+
+    fn restore(&mut self, position: Position) {
+        let card = match self.foundation.pop() {
+            Some(face) => face.card(),
+            None => return,
+        };
+        match position {
+            Position::Pyramid(index) => self.pyramid[index as usize] = Some(card),
+            Position::Stock => self.stock.push(Face::Down(card)),
+            Position::Waste => self.waste.push(Face::Up(card)),
+        }
+    }
+}
+
+/// Returns the indices of the two cards covering the card at an index, unless it is in the
+/// bottom row.
+fn covering(index: usize) -> Option<(usize, usize)> {
+    let mut row = 0;
+    while (row + 1) * (row + 2) / 2 <= index {
+        row += 1;
+    }
+    if row == 6 {
+        None
+    } else {
+        let below = index + row + 1;
+        Some((below, below + 1))
+    }
+}
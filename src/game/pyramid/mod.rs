@@ -0,0 +1,61 @@
+//! Pyramid solitaire.
+
+use card::{Card, Pile};
+
+/// Pyramid solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pyramid {
+    rules: Rules,
+    redeals: u8,
+    stock: Pile,
+    waste: Pile,
+    foundation: Pile,
+    pyramid: [Option<Card>; 28],
+}
+
+/// Pyramid variation rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Number of times the waste may be turned back into the stock.
+    pub redeals: u8,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules { redeals: 2 }
+    }
+}
+
+/// Pyramid play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Draw from stock into waste.
+    Draw,
+
+    /// Move waste back to stock.
+    Redeal,
+
+    /// Remove two available cards whose ranks sum to 13.
+    Pair(Position, Position),
+
+    /// Remove an available king.
+    King(Position),
+}
+
+/// Position of an available card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// Card in the pyramid, numbered from the apex row by row, left to right.
+    Pyramid(u8),
+
+    /// Top card of the stock.
+    Stock,
+
+    /// Top card of the waste.
+    Waste,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
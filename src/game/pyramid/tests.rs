@@ -0,0 +1,240 @@
+mod is_valid {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::pyramid::{Pyramid, Rules, Play, Position};
+
+    #[test]
+    fn valid_draw_full_stock() {
+        let game = Pyramid::new(Rules::default());
+        assert!(game.is_valid(&Play::Draw));
+    }
+
+    #[test]
+    fn invalid_draw_empty_stock() {
+        let mut game = Pyramid::new(Rules::default());
+        game.stock = Pile::new();
+        assert!(!game.is_valid(&Play::Draw));
+    }
+
+    #[test]
+    fn valid_redeal_empty_stock() {
+        let mut game = Pyramid::new(Rules::default());
+        game.stock.deal_to(&mut game.waste, 52, true);
+        assert!(game.is_valid(&Play::Redeal));
+    }
+
+    #[test]
+    fn invalid_redeal_non_empty_stock() {
+        let mut game = Pyramid::new(Rules::default());
+        game.stock.deal_to(&mut game.waste, 1, true);
+        assert!(!game.is_valid(&Play::Redeal));
+    }
+
+    #[test]
+    fn invalid_redeal_exhausted() {
+        let mut game = Pyramid::new(Rules { redeals: 1 });
+        game.stock.deal_to(&mut game.waste, 52, true);
+        game.play(&Play::Redeal);
+        game.stock.deal_to(&mut game.waste, 52, true);
+        assert!(!game.is_valid(&Play::Redeal));
+    }
+
+    #[test]
+    fn valid_pair_pyramid_bottom_row() {
+        let mut game = Pyramid::new(Rules::default());
+        game.pyramid[21] = Some(card!(H 6));
+        game.pyramid[22] = Some(card!(S 7));
+        assert!(game.is_valid(&Play::Pair(Position::Pyramid(21), Position::Pyramid(22))));
+    }
+
+    #[test]
+    fn valid_pair_pyramid_uncovered() {
+        let mut game = Pyramid::new(Rules::default());
+        game.pyramid[0] = Some(card!(H Q));
+        game.waste.push(Face::Up(card!(S A)));
+        assert!(game.is_valid(&Play::Pair(Position::Pyramid(0), Position::Waste)));
+    }
+
+    #[test]
+    fn valid_pair_stock_waste() {
+        let mut game = Pyramid::new(Rules::default());
+        game.stock = Pile::new();
+        game.stock.push(Face::Down(card!(C 5)));
+        game.waste.push(Face::Up(card!(D 8)));
+        assert!(game.is_valid(&Play::Pair(Position::Stock, Position::Waste)));
+    }
+
+    #[test]
+    fn invalid_pair_covered() {
+        let mut game = Pyramid::new(Rules::default());
+        game.pyramid[15] = Some(card!(H 6));
+        game.pyramid[21] = Some(card!(S 7));
+        assert!(!game.is_valid(&Play::Pair(Position::Pyramid(15), Position::Pyramid(21))));
+    }
+
+    #[test]
+    fn invalid_pair_half_covered() {
+        let mut game = Pyramid::new(Rules::default());
+        game.pyramid[15] = Some(card!(H 6));
+        game.pyramid[22] = Some(card!(C 3));
+        game.waste.push(Face::Up(card!(S 7)));
+        assert!(!game.is_valid(&Play::Pair(Position::Pyramid(15), Position::Waste)));
+    }
+
+    #[test]
+    fn invalid_pair_non_thirteen() {
+        let mut game = Pyramid::new(Rules::default());
+        game.pyramid[21] = Some(card!(H 6));
+        game.pyramid[22] = Some(card!(S 8));
+        assert!(!game.is_valid(&Play::Pair(Position::Pyramid(21), Position::Pyramid(22))));
+    }
+
+    #[test]
+    fn invalid_pair_same_position() {
+        let mut game = Pyramid::new(Rules::default());
+        game.stock = Pile::new();
+        game.waste.push(Face::Up(card!(S 7)));
+        assert!(!game.is_valid(&Play::Pair(Position::Waste, Position::Waste)));
+    }
+
+    #[test]
+    fn invalid_pair_empty() {
+        let game = Pyramid::new(Rules::default());
+        assert!(!game.is_valid(&Play::Pair(Position::Pyramid(21), Position::Pyramid(22))));
+    }
+
+    #[test]
+    fn invalid_pair_out_of_range() {
+        let mut game = Pyramid::new(Rules::default());
+        game.waste.push(Face::Up(card!(S 7)));
+        assert!(!game.is_valid(&Play::Pair(Position::Pyramid(28), Position::Waste)));
+    }
+
+    #[test]
+    fn valid_king() {
+        let mut game = Pyramid::new(Rules::default());
+        game.pyramid[27] = Some(card!(D K));
+        assert!(game.is_valid(&Play::King(Position::Pyramid(27))));
+    }
+
+    #[test]
+    fn invalid_king_non_king() {
+        let mut game = Pyramid::new(Rules::default());
+        game.pyramid[27] = Some(card!(D Q));
+        assert!(!game.is_valid(&Play::King(Position::Pyramid(27))));
+    }
+}
+
+mod play {
+    use card::Face;
+    use game::Game;
+    use game::pyramid::{Pyramid, Rules, Play, Position};
+
+    #[test]
+    fn deal() {
+        let mut game = Pyramid::new(Rules::default());
+        game.deal();
+        assert!(game.pyramid.iter().all(Option::is_some));
+        assert_eq!(24, game.stock.count());
+    }
+
+    #[test]
+    fn draw() {
+        let mut game = Pyramid::new(Rules::default());
+        let top = game.stock.top();
+        game.play(&Play::Draw);
+        assert_eq!(top.map(Face::flipped), game.waste.top());
+    }
+
+    #[test]
+    fn redeal() {
+        let mut game = Pyramid::new(Rules::default());
+        let stock = game.stock.clone();
+        while !game.stock.is_empty() {
+            game.play(&Play::Draw);
+        }
+        game.play(&Play::Redeal);
+        assert_eq!(stock, game.stock);
+        assert_eq!(1, game.redeals);
+    }
+
+    #[test]
+    fn pair() {
+        let mut game = Pyramid::new(Rules::default());
+        game.pyramid[21] = Some(card!(H 6));
+        game.waste.push(Face::Up(card!(S 7)));
+        game.play(&Play::Pair(Position::Pyramid(21), Position::Waste));
+        assert_eq!(None, game.pyramid[21]);
+        assert!(game.waste.is_empty());
+        assert_eq!(2, game.foundation.count());
+    }
+
+    #[test]
+    fn king() {
+        let mut game = Pyramid::new(Rules::default());
+        game.stock.push(Face::Down(card!(S K)));
+        game.play(&Play::King(Position::Stock));
+        assert_eq!(Some(Face::Up(card!(S K))), game.foundation.top());
+    }
+
+    #[test]
+    fn won() {
+        let mut game = Pyramid::new(Rules::default());
+        game.pyramid[0] = Some(card!(S K));
+        assert!(!game.is_won());
+        game.play(&Play::King(Position::Pyramid(0)));
+        assert!(game.is_won());
+    }
+}
+
+mod undo {
+    use card::Face;
+    use game::Game;
+    use game::pyramid::{Pyramid, Rules, Play, Position};
+
+    #[test]
+    fn draw() {
+        let mut before = Pyramid::new(Rules::default());
+        before.deal();
+        let mut after = before.clone();
+        after.play(&Play::Draw);
+        after.undo(&Play::Draw);
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn redeal() {
+        let mut before = Pyramid::new(Rules::default());
+        before.deal();
+        while !before.stock.is_empty() {
+            before.play(&Play::Draw);
+        }
+        let mut after = before.clone();
+        after.play(&Play::Redeal);
+        after.undo(&Play::Redeal);
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn pair() {
+        let mut before = Pyramid::new(Rules::default());
+        before.deal();
+        before.pyramid[21] = Some(card!(H 6));
+        before.waste.push(Face::Up(card!(S 7)));
+        let mut after = before.clone();
+        let play = Play::Pair(Position::Pyramid(21), Position::Waste);
+        after.play(&play);
+        after.undo(&play);
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn king() {
+        let mut before = Pyramid::new(Rules::default());
+        before.stock.push(Face::Down(card!(S K)));
+        let mut after = before.clone();
+        after.play(&Play::King(Position::Stock));
+        after.undo(&Play::King(Position::Stock));
+        assert_eq!(before, after);
+    }
+}
@@ -0,0 +1,138 @@
+use card::{Rank, Face, Set, Pile};
+use game::Game;
+use super::{Scorpion, Play, Tableau};
+
+impl Game for Scorpion {
+    type Rules = ();
+    type Play = Play;
+
+    fn new(_: ()) -> Self {
+        Scorpion {
+            reserve: Set::new().map(Face::Down).collect(),
+            foundations: [Pile::new(), Pile::new(), Pile::new(), Pile::new()],
+            tableau: [
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+                Pile::new(),
+            ],
+        }
+    }
+
+    fn deal(&mut self) {
+        self.reserve.shuffle();
+        for row in 0..7 {
+            for (i, pile) in self.tableau.iter_mut().enumerate() {
+                self.reserve.deal_to(pile, 1, row >= 3 || i >= 4);
+            }
+        }
+    }
+
+    fn is_won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.count() == 13)
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::Reserve => {
+                !self.reserve.is_empty()
+            },
+
+            Play::Reveal(tableau) => {
+                self.tableau[tableau as usize].top().as_ref().map_or(false, Face::is_down)
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.foundations[foundation as usize].is_empty()
+                    && self.is_complete(tableau)
+            },
+
+            Play::TableauTableau(src, count, dest) => {
+                src != dest && self.tableau[src as usize].get_back(count as usize).map_or(false, |face| {
+                    face.is_up() && match self.tableau[dest as usize].top() {
+                        None => face.card().rank == Rank::King,
+                        Some(top) => {
+                            top.is_up()
+                                && face.card().suit == top.card().suit
+                                && face.card().rank.succ() == Some(top.card().rank)
+                        },
+                    }
+                })
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::Reserve => {
+                for pile in &mut self.tableau[..3] {
+                    self.reserve.deal_to(pile, 1, true);
+                }
+            },
+
+            Play::Reveal(tableau) => {
+                self.tableau[tableau as usize].flip_top();
+            },
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.tableau[tableau as usize].move_to(
+                    &mut self.foundations[foundation as usize],
+                    13,
+                );
+            },
+
+            Play::TableauTableau(src, count, dest) => {
+                if src < dest {
+                    let (left, right) = self.tableau.split_at_mut(dest as usize);
+                    left[src as usize].move_to(&mut right[0], count as usize);
+                } else {
+                    let (left, right) = self.tableau.split_at_mut(src as usize);
+                    right[0].move_to(&mut left[dest as usize], count as usize);
+                }
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::Reserve => {
+                for pile in self.tableau[..3].iter_mut().rev() {
+                    pile.deal_to(&mut self.reserve, 1, true);
+                }
+            },
+
+            Play::Reveal(_) => self.play(play),
+
+            Play::TableauFoundation(tableau, foundation) => {
+                self.foundations[foundation as usize].move_to(
+                    &mut self.tableau[tableau as usize],
+                    13,
+                );
+            },
+
+            Play::TableauTableau(src, count, dest) => {
+                self.play(&Play::TableauTableau(dest, count, src));
+            },
+        }
+    }
+}
+
+impl Scorpion {
+    /// Returns true if the top 13 cards of a tableau are a face-up king-to-ace suit.
+    fn is_complete(&self, tableau: Tableau) -> bool {
+        let pile = &self.tableau[tableau as usize];
+        if pile.count() < 13 {
+            return false;
+        }
+        let cards = &pile.vec[pile.count() - 13..];
+        cards[0].card().rank == Rank::King
+            && cards.iter().all(Face::is_up)
+            && cards.windows(2).all(|pair| {
+                let (top, card) = (pair[0].card(), pair[1].card());
+                card.suit == top.suit && card.rank.succ() == Some(top.rank)
+            })
+    }
+}
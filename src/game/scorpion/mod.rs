@@ -0,0 +1,55 @@
+//! Scorpion solitaire.
+
+use card::Pile;
+
+/// Scorpion solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scorpion {
+    reserve: Pile,
+    foundations: [Pile; 4],
+    tableau: [Pile; 7],
+}
+
+/// Scorpion play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Deal the reserve onto the first three tableaux.
+    Reserve,
+
+    /// Reveal the top card of a tableau pile.
+    Reveal(Tableau),
+
+    /// Move a completed king-to-ace suit from tableau to foundation.
+    TableauFoundation(Tableau, Foundation),
+
+    /// Move a face-up card and every card on top of it from tableau to tableau.
+    TableauTableau(Tableau, u8, Tableau),
+}
+
+/// Foundations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Foundation {
+    One,
+    Two,
+    Three,
+    Four,
+}
+
+/// Tableaux.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Tableau {
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,170 @@
+mod is_valid {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::scorpion::{Scorpion, Play, Foundation, Tableau};
+
+    #[test]
+    fn valid_reserve() {
+        let game = Scorpion::new(());
+        assert!(game.is_valid(&Play::Reserve));
+    }
+
+    #[test]
+    fn invalid_reserve_empty() {
+        let mut game = Scorpion::new(());
+        game.reserve = Pile::new();
+        assert!(!game.is_valid(&Play::Reserve));
+    }
+
+    #[test]
+    fn valid_reveal() {
+        let mut game = Scorpion::new(());
+        game.tableau[0].push(Face::Down(card!(S 5)));
+        assert!(game.is_valid(&Play::Reveal(Tableau::One)));
+    }
+
+    #[test]
+    fn invalid_reveal_up() {
+        let mut game = Scorpion::new(());
+        game.tableau[0].push(Face::Up(card!(S 5)));
+        assert!(!game.is_valid(&Play::Reveal(Tableau::One)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau_suit_pred() {
+        let mut game = Scorpion::new(());
+        game.tableau[0].push(Face::Up(card!(S 5)));
+        game.tableau[1].push(Face::Up(card!(S 6)));
+        assert!(game.is_valid(&Play::TableauTableau(Tableau::One, 1, Tableau::Two)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau_unordered() {
+        let mut game = Scorpion::new(());
+        game.tableau[0].push(Face::Up(card!(S 5)));
+        game.tableau[0].push(Face::Up(card!(H 2)));
+        game.tableau[0].push(Face::Up(card!(C Q)));
+        game.tableau[1].push(Face::Up(card!(S 6)));
+        assert!(game.is_valid(&Play::TableauTableau(Tableau::One, 3, Tableau::Two)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_non_suit() {
+        let mut game = Scorpion::new(());
+        game.tableau[0].push(Face::Up(card!(C 5)));
+        game.tableau[1].push(Face::Up(card!(S 6)));
+        assert!(!game.is_valid(&Play::TableauTableau(Tableau::One, 1, Tableau::Two)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_face_down() {
+        let mut game = Scorpion::new(());
+        game.tableau[0].push(Face::Down(card!(S 5)));
+        game.tableau[0].push(Face::Up(card!(H 2)));
+        game.tableau[1].push(Face::Up(card!(S 6)));
+        assert!(!game.is_valid(&Play::TableauTableau(Tableau::One, 2, Tableau::Two)));
+    }
+
+    #[test]
+    fn valid_tableau_tableau_king_empty() {
+        let mut game = Scorpion::new(());
+        game.tableau[0].push(Face::Up(card!(S K)));
+        assert!(game.is_valid(&Play::TableauTableau(Tableau::One, 1, Tableau::Two)));
+    }
+
+    #[test]
+    fn invalid_tableau_tableau_non_king_empty() {
+        let mut game = Scorpion::new(());
+        game.tableau[0].push(Face::Up(card!(S Q)));
+        assert!(!game.is_valid(&Play::TableauTableau(Tableau::One, 1, Tableau::Two)));
+    }
+
+    #[test]
+    fn valid_tableau_foundation() {
+        let mut game = Scorpion::new(());
+        game.tableau[0].push(Face::Down(card!(C 3)));
+        game.tableau[0].vec.extend(game.reserve.vec.drain(26..39).map(Face::flipped));
+        assert!(game.is_valid(&Play::TableauFoundation(Tableau::One, Foundation::One)));
+    }
+
+    #[test]
+    fn invalid_tableau_foundation_incomplete() {
+        let mut game = Scorpion::new(());
+        game.tableau[0].vec.extend(game.reserve.vec.drain(26..38).map(Face::flipped));
+        assert!(!game.is_valid(&Play::TableauFoundation(Tableau::One, Foundation::One)));
+    }
+}
+
+mod play {
+    use card::Face;
+    use game::Game;
+    use game::scorpion::{Scorpion, Play, Tableau};
+
+    #[test]
+    fn deal() {
+        let mut game = Scorpion::new(());
+        game.deal();
+        assert_eq!(3, game.reserve.count());
+        assert!(game.tableau.iter().all(|pile| pile.count() == 7));
+        for (i, pile) in game.tableau.iter().enumerate() {
+            assert_eq!(i < 4, pile.get(2).unwrap().is_down());
+            assert!(pile.get(3).unwrap().is_up());
+        }
+    }
+
+    #[test]
+    fn reserve() {
+        let mut game = Scorpion::new(());
+        game.deal();
+        let (a, b, c) = (game.reserve.get_back(1), game.reserve.get_back(2), game.reserve.get_back(3));
+        game.play(&Play::Reserve);
+        assert!(game.reserve.is_empty());
+        assert_eq!(a.map(Face::flipped), game.tableau[0].top());
+        assert_eq!(b.map(Face::flipped), game.tableau[1].top());
+        assert_eq!(c.map(Face::flipped), game.tableau[2].top());
+    }
+
+    #[test]
+    fn tableau_tableau() {
+        let mut game = Scorpion::new(());
+        game.deal();
+        let (a, b) = (game.tableau[6].get_back(2), game.tableau[6].get_back(1));
+        game.play(&Play::TableauTableau(Tableau::Seven, 2, Tableau::One));
+        assert_eq!(a, game.tableau[0].get_back(2));
+        assert_eq!(b, game.tableau[0].get_back(1));
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::scorpion::{Scorpion, Play, Foundation, Tableau};
+
+    macro_rules! undo_test {
+        ($name:ident, $play:expr) => {
+            #[test]
+            fn $name() {
+                let mut before = Scorpion::new(());
+                before.deal();
+                let mut after = before.clone();
+                after.play(&$play);
+                after.undo(&$play);
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    #[test]
+    fn tableau_foundation() {
+        let mut before = Scorpion::new(());
+        before.tableau[0].vec.extend(before.reserve.vec.drain(26..39));
+        let mut after = before.clone();
+        after.play(&Play::TableauFoundation(Tableau::One, Foundation::One));
+        assert!(after.tableau[0].is_empty());
+        after.undo(&Play::TableauFoundation(Tableau::One, Foundation::One));
+        assert_eq!(before, after);
+    }
+
+    undo_test!(reserve, Play::Reserve);
+    undo_test!(reveal, Play::Reveal(Tableau::One));
+    undo_test!(tableau_tableau, Play::TableauTableau(Tableau::Seven, 3, Tableau::Two));
+}
@@ -0,0 +1,146 @@
+use card::{Card, Face, Set, Pile};
+use game::Game;
+use super::{TriPeaks, Rules, Play};
+
+/// Indices of the two cards covering each card above the bottom row.
+const COVERING: [[usize; 2]; 18] = [
+    [3, 4], [5, 6], [7, 8],
+    [9, 10], [10, 11], [12, 13], [13, 14], [15, 16], [16, 17],
+    [18, 19], [19, 20], [20, 21], [21, 22], [22, 23], [23, 24], [24, 25], [25, 26], [26, 27],
+];
+
+impl Game for TriPeaks {
+    type Rules = Rules;
+    type Play = Play;
+
+    fn new(rules: Rules) -> Self {
+        TriPeaks {
+            rules: rules,
+            stock: Set::new().map(Face::Down).collect(),
+            waste: Pile::new(),
+            tableau: [None; 28],
+            score: 0,
+            streak: 0,
+            streaks: Vec::new(),
+        }
+    }
+
+    fn deal(&mut self) {
+        self.stock.shuffle();
+        for index in 0..self.tableau.len() {
+            self.tableau[index] = self.stock.pop();
+            if index >= COVERING.len() {
+                let _ = self.tableau[index].as_mut().map(Face::flip);
+            }
+        }
+        self.stock.deal_to(&mut self.waste, 1, true);
+    }
+
+    fn is_won(&self) -> bool {
+        self.tableau.iter().all(Option::is_none)
+    }
+
+    fn is_valid(&self, play: &Play) -> bool {
+        match *play {
+            Play::Draw => {
+                !self.stock.is_empty()
+            },
+
+            Play::TableauWaste(index) => {
+                let index = index as usize;
+                index < self.tableau.len()
+                    && !self.is_covered(index)
+                    && self.tableau[index].map_or(false, |face| {
+                        self.is_valid_waste(face.card())
+                    })
+            },
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        match *play {
+            Play::Draw => {
+                self.stock.deal_to(&mut self.waste, 1, true);
+                self.streaks.push(self.streak);
+                self.streak = 0;
+            },
+
+            Play::TableauWaste(index) => {
+                let index = index as usize;
+                if let Some(face) = self.tableau[index].take() {
+                    self.waste.push(Face::Up(face.card()));
+                }
+                for parent in parents(index) {
+                    if self.is_covered(parent) {
+                        continue;
+                    }
+                    if let Some(face) = self.tableau[parent].as_mut() {
+                        *face = Face::Up(face.card());
+                    }
+                }
+                self.streak += 1;
+                self.score += self.streak;
+            },
+        }
+    }
+
+    fn undo(&mut self, play: &Play) {
+        match *play {
+            Play::Draw => {
+                self.waste.deal_to(&mut self.stock, 1, true);
+                self.streak = self.streaks.pop().unwrap_or(0);
+            },
+
+            Play::TableauWaste(index) => {
+                let index = index as usize;
+                self.tableau[index] = self.waste.pop();
+                for parent in parents(index) {
+                    if let Some(face) = self.tableau[parent].as_mut() {
+                        *face = Face::Down(face.card());
+                    }
+                }
+                self.score -= self.streak;
+                self.streak -= 1;
+            },
+        }
+    }
+}
+
+impl TriPeaks {
+    /// Returns the score, where each card moved to waste scores its position in the current
+    /// streak.
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    /// Returns the number of cards moved to waste since the last draw.
+    pub fn streak(&self) -> u32 {
+        self.streak
+    }
+
+    fn is_covered(&self, index: usize) -> bool {
+        COVERING.get(index).map_or(false, |covering| {
+            covering.iter().any(|&i| self.tableau[i].is_some())
+        })
+    }
+
+    fn is_valid_waste(&self, card: Card) -> bool {
+        let top = match self.waste.top() {
+            Some(face) => face.card(),
+            None => return false,
+        };
+
+        if self.rules.wrap {
+            card.rank.succ_wrapping() == top.rank || card.rank.pred_wrapping() == top.rank
+        } else {
+            card.rank.succ() == Some(top.rank) || card.rank.pred() == Some(top.rank)
+        }
+    }
+}
+
+/// Returns the indices of the cards covered by the card at an index.
+fn parents(index: usize) -> Vec<usize> {
+    (0..COVERING.len())
+        .filter(|&i| COVERING[i].contains(&index))
+        .collect()
+}
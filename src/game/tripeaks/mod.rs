@@ -0,0 +1,45 @@
+//! TriPeaks solitaire.
+
+use card::{Face, Pile};
+
+/// TriPeaks solitaire game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TriPeaks {
+    rules: Rules,
+    stock: Pile,
+    waste: Pile,
+    tableau: [Option<Face>; 28],
+    score: u32,
+    streak: u32,
+    streaks: Vec<u32>,
+}
+
+/// TriPeaks variation rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Kings and aces are adjacent, so either may be played on the other.
+    pub wrap: bool,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules { wrap: true }
+    }
+}
+
+/// TriPeaks play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    /// Draw from stock into waste, ending the streak.
+    Draw,
+
+    /// Move an uncovered tableau card to waste.
+    ///
+    /// The tableau is numbered from the peaks down, row by row, left to right.
+    TableauWaste(u8),
+}
+
+mod game;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,176 @@
+mod is_valid {
+    use card::{Face, Pile};
+    use game::Game;
+    use game::tripeaks::{TriPeaks, Rules, Play};
+
+    #[test]
+    fn valid_draw_full_stock() {
+        let game = TriPeaks::new(Rules::default());
+        assert!(game.is_valid(&Play::Draw));
+    }
+
+    #[test]
+    fn invalid_draw_empty_stock() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.stock = Pile::new();
+        assert!(!game.is_valid(&Play::Draw));
+    }
+
+    #[test]
+    fn valid_tableau_waste_succ() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 7)));
+        game.tableau[18] = Some(Face::Up(card!(S 8)));
+        assert!(game.is_valid(&Play::TableauWaste(18)));
+    }
+
+    #[test]
+    fn valid_tableau_waste_pred() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 7)));
+        game.tableau[18] = Some(Face::Up(card!(S 6)));
+        assert!(game.is_valid(&Play::TableauWaste(18)));
+    }
+
+    #[test]
+    fn valid_tableau_waste_wrap() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.waste.push(Face::Up(card!(H K)));
+        game.tableau[18] = Some(Face::Up(card!(S A)));
+        assert!(game.is_valid(&Play::TableauWaste(18)));
+    }
+
+    #[test]
+    fn invalid_tableau_waste_no_wrap() {
+        let mut game = TriPeaks::new(Rules { wrap: false });
+        game.waste.push(Face::Up(card!(H K)));
+        game.tableau[18] = Some(Face::Up(card!(S A)));
+        assert!(!game.is_valid(&Play::TableauWaste(18)));
+    }
+
+    #[test]
+    fn invalid_tableau_waste_non_adjacent() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 7)));
+        game.tableau[18] = Some(Face::Up(card!(S 9)));
+        assert!(!game.is_valid(&Play::TableauWaste(18)));
+    }
+
+    #[test]
+    fn invalid_tableau_waste_covered() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 7)));
+        game.tableau[9] = Some(Face::Up(card!(S 8)));
+        game.tableau[19] = Some(Face::Up(card!(S 2)));
+        assert!(!game.is_valid(&Play::TableauWaste(9)));
+    }
+
+    #[test]
+    fn valid_tableau_waste_uncovered() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 7)));
+        game.tableau[0] = Some(Face::Up(card!(S 8)));
+        assert!(game.is_valid(&Play::TableauWaste(0)));
+    }
+
+    #[test]
+    fn invalid_tableau_waste_empty() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 7)));
+        assert!(!game.is_valid(&Play::TableauWaste(18)));
+    }
+
+    #[test]
+    fn invalid_tableau_waste_out_of_range() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.waste.push(Face::Up(card!(H 7)));
+        assert!(!game.is_valid(&Play::TableauWaste(28)));
+    }
+}
+
+mod play {
+    use card::Face;
+    use game::Game;
+    use game::tripeaks::{TriPeaks, Rules, Play};
+
+    #[test]
+    fn deal() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.deal();
+        assert!(game.tableau[..18].iter().all(|face| face.map_or(false, |f| f.is_down())));
+        assert!(game.tableau[18..].iter().all(|face| face.map_or(false, |f| f.is_up())));
+        assert_eq!(1, game.waste.count());
+        assert_eq!(23, game.stock.count());
+    }
+
+    #[test]
+    fn draw() {
+        let mut game = TriPeaks::new(Rules::default());
+        let top = game.stock.top();
+        game.play(&Play::Draw);
+        assert_eq!(top.map(Face::flipped), game.waste.top());
+    }
+
+    #[test]
+    fn tableau_waste() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.deal();
+        let card = game.tableau[18];
+        game.play(&Play::TableauWaste(18));
+        assert_eq!(card, game.waste.top());
+        assert_eq!(None, game.tableau[18]);
+    }
+
+    #[test]
+    fn tableau_waste_flips_uncovered() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.deal();
+        game.play(&Play::TableauWaste(18));
+        assert!(game.tableau[9].unwrap().is_down());
+        game.play(&Play::TableauWaste(19));
+        assert!(game.tableau[9].unwrap().is_up());
+        assert!(game.tableau[10].unwrap().is_down());
+    }
+
+    #[test]
+    fn streak_scoring() {
+        let mut game = TriPeaks::new(Rules::default());
+        game.deal();
+        game.play(&Play::TableauWaste(18));
+        game.play(&Play::TableauWaste(19));
+        game.play(&Play::TableauWaste(20));
+        assert_eq!(3, game.streak());
+        assert_eq!(6, game.score());
+        game.play(&Play::Draw);
+        assert_eq!(0, game.streak());
+        game.play(&Play::TableauWaste(21));
+        assert_eq!(7, game.score());
+    }
+}
+
+mod undo {
+    use game::Game;
+    use game::tripeaks::{TriPeaks, Rules, Play};
+
+    #[test]
+    fn draw() {
+        let mut before = TriPeaks::new(Rules::default());
+        before.deal();
+        before.play(&Play::TableauWaste(18));
+        let mut after = before.clone();
+        after.play(&Play::Draw);
+        after.undo(&Play::Draw);
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn tableau_waste() {
+        let mut before = TriPeaks::new(Rules::default());
+        before.deal();
+        before.play(&Play::TableauWaste(18));
+        let mut after = before.clone();
+        after.play(&Play::TableauWaste(19));
+        after.undo(&Play::TableauWaste(19));
+        assert_eq!(before, after);
+    }
+}
@@ -0,0 +1,517 @@
+//! Terminal Klondike.
+//!
+//! Usage: `patience [--three] [--ascii] [SEED]`
+
+extern crate patience;
+extern crate rand;
+
+use std::env;
+use std::io::{self, Read, Write};
+use std::process::{self, Command, Stdio};
+
+use patience::card::Pile;
+use patience::game::Game;
+use patience::game::klondike::{Klondike, Draw, Play, Target, Foundation, Tableau};
+use patience::ui::{Canvas, Width, Height, Render};
+use patience::ui::ascii::{self, Ascii};
+use patience::ui::terminal::{self, Terminal};
+
+const FOUNDATIONS: [Foundation; 4] = [
+    Foundation::One,
+    Foundation::Two,
+    Foundation::Three,
+    Foundation::Four,
+];
+
+const TABLEAUX: [Tableau; 7] = [
+    Tableau::One,
+    Tableau::Two,
+    Tableau::Three,
+    Tableau::Four,
+    Tableau::Five,
+    Tableau::Six,
+    Tableau::Seven,
+];
+
+/// Pile under the cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Spot {
+    Stock,
+    Waste,
+    Foundation(Foundation),
+    Tableau(Tableau),
+}
+
+impl Spot {
+    /// Returns the target of the top card of the pile.
+    fn target(self) -> Target {
+        match self {
+            Spot::Stock => Target::Stock,
+            Spot::Waste => Target::Waste,
+            Spot::Foundation(foundation) => Target::Foundation(foundation),
+            Spot::Tableau(tableau) => Target::Tableau(tableau, 0),
+        }
+    }
+
+    /// Returns the layout column of the pile.
+    fn column(self) -> u16 {
+        match self {
+            Spot::Stock => 0,
+            Spot::Waste => 1,
+            Spot::Foundation(foundation) => 3 + foundation as u16,
+            Spot::Tableau(tableau) => tableau as u16,
+        }
+    }
+
+    fn left(self) -> Self {
+        match self {
+            Spot::Stock => Spot::Foundation(Foundation::Four),
+            Spot::Waste => Spot::Stock,
+            Spot::Foundation(Foundation::One) => Spot::Waste,
+            Spot::Foundation(f) => Spot::Foundation(FOUNDATIONS[f as usize - 1]),
+            Spot::Tableau(Tableau::One) => Spot::Tableau(Tableau::Seven),
+            Spot::Tableau(t) => Spot::Tableau(TABLEAUX[t as usize - 1]),
+        }
+    }
+
+    fn right(self) -> Self {
+        match self {
+            Spot::Stock => Spot::Waste,
+            Spot::Waste => Spot::Foundation(Foundation::One),
+            Spot::Foundation(Foundation::Four) => Spot::Stock,
+            Spot::Foundation(f) => Spot::Foundation(FOUNDATIONS[f as usize + 1]),
+            Spot::Tableau(Tableau::Seven) => Spot::Tableau(Tableau::One),
+            Spot::Tableau(t) => Spot::Tableau(TABLEAUX[t as usize + 1]),
+        }
+    }
+
+    fn up(self) -> Self {
+        match self {
+            Spot::Tableau(Tableau::One) => Spot::Stock,
+            Spot::Tableau(Tableau::Two) | Spot::Tableau(Tableau::Three) => Spot::Waste,
+            Spot::Tableau(t) => Spot::Foundation(FOUNDATIONS[t as usize - 3]),
+            target => target,
+        }
+    }
+
+    fn down(self) -> Self {
+        match self {
+            Spot::Tableau(_) => self,
+            target => Spot::Tableau(TABLEAUX[target.column() as usize]),
+        }
+    }
+}
+
+/// Keyboard command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Left,
+    Right,
+    Up,
+    Down,
+    Select,
+    Smart,
+    Cancel,
+    Draw,
+    Redeal,
+    Undo,
+    New,
+    Quit,
+}
+
+impl Key {
+    /// Parses the first key in `bytes`, returning it with the number of bytes it used.
+    fn parse(bytes: &[u8]) -> (Option<Self>, usize) {
+        match bytes {
+            [b'\x1b', b'[', code, ..] => {
+                let key = match *code {
+                    b'D' => Some(Key::Left),
+                    b'C' => Some(Key::Right),
+                    b'A' => Some(Key::Up),
+                    b'B' => Some(Key::Down),
+                    _ => None,
+                };
+                (key, 3)
+            },
+            [byte, ..] => {
+                let key = match *byte {
+                    b'h' => Some(Key::Left),
+                    b'l' => Some(Key::Right),
+                    b'k' => Some(Key::Up),
+                    b'j' => Some(Key::Down),
+                    b' ' => Some(Key::Select),
+                    b'\r' => Some(Key::Smart),
+                    b'\x1b' => Some(Key::Cancel),
+                    b'd' => Some(Key::Draw),
+                    b'r' => Some(Key::Redeal),
+                    b'u' => Some(Key::Undo),
+                    b'n' => Some(Key::New),
+                    b'q' | b'\x03' => Some(Key::Quit),
+                    _ => None,
+                };
+                (key, 1)
+            },
+            [] => (None, 0),
+        }
+    }
+}
+
+/// Game session.
+#[derive(Debug)]
+struct App {
+    game: Klondike,
+    history: Vec<Play>,
+    cursor: Spot,
+    selected: Option<Spot>,
+    message: &'static str,
+}
+
+impl App {
+    fn new(draw: Draw, seed: u32) -> Self {
+        let mut game = Klondike::with_seed(draw, seed);
+        game.deal();
+        App {
+            game: game,
+            history: Vec::new(),
+            cursor: Spot::Stock,
+            selected: None,
+            message: "",
+        }
+    }
+
+    /// Handles a key, returning false to quit.
+    fn key(&mut self, key: Key) -> bool {
+        self.message = "";
+        match key {
+            Key::Left => self.cursor = self.cursor.left(),
+            Key::Right => self.cursor = self.cursor.right(),
+            Key::Up => self.cursor = self.cursor.up(),
+            Key::Down => self.cursor = self.cursor.down(),
+            Key::Select => self.select(),
+            Key::Smart => {
+                self.selected = None;
+                match self.game.smart_move(self.cursor.target()) {
+                    Some(play) => self.play(play),
+                    None => self.message = "No move",
+                }
+            },
+            Key::Cancel => self.selected = None,
+            Key::Draw => self.play(Play::Draw),
+            Key::Redeal => self.play(Play::Redeal),
+            Key::Undo => self.undo(),
+            Key::New => *self = App::new(self.game.draw(), rand::random()),
+            Key::Quit => return false,
+        }
+        true
+    }
+
+    fn select(&mut self) {
+        match (self.selected, self.cursor) {
+            (None, Spot::Stock) => {
+                if self.game.is_valid(&Play::Draw) {
+                    self.play(Play::Draw);
+                } else {
+                    self.play(Play::Redeal);
+                }
+            },
+
+            (None, target) => {
+                if !self.pile(target).is_empty() {
+                    self.selected = Some(target);
+                }
+            },
+
+            (Some(src), dest) if src == dest => self.selected = None,
+
+            (Some(src), dest) => {
+                self.selected = None;
+                match self.resolve(src, dest) {
+                    Some(play) => self.play(play),
+                    None => self.message = "Invalid move",
+                }
+            },
+        }
+    }
+
+    fn pile(&self, target: Spot) -> &Pile {
+        match target {
+            Spot::Stock => self.game.stock(),
+            Spot::Waste => self.game.waste(),
+            Spot::Foundation(foundation) => self.game.foundation(foundation),
+            Spot::Tableau(tableau) => self.game.tableau(tableau),
+        }
+    }
+
+    /// Finds a valid play moving cards from one pile to another, trying each depth of a tableau
+    /// pile.
+    fn resolve(&self, src: Spot, dest: Spot) -> Option<Play> {
+        match src {
+            Spot::Tableau(tableau) => {
+                (0..self.game.tableau(tableau).count() as u8)
+                    .filter_map(|depth| {
+                        self.game.resolve(Target::Tableau(tableau, depth), dest.target())
+                    })
+                    .next()
+            },
+            src => self.game.resolve(src.target(), dest.target()),
+        }
+    }
+
+    /// Performs a play and reveals uncovered tableau cards.
+    fn play(&mut self, play: Play) {
+        if !self.game.is_valid(&play) {
+            self.message = "Invalid move";
+            return;
+        }
+        self.game.play(&play);
+        self.history.push(play);
+
+        for &tableau in &TABLEAUX {
+            let reveal = Play::Reveal(tableau);
+            if self.game.is_valid(&reveal) {
+                self.game.play(&reveal);
+                self.history.push(reveal);
+            }
+        }
+
+        if self.game.is_won() {
+            self.message = "You won! Press n for a new game.";
+        }
+    }
+
+    fn undo(&mut self) {
+        self.selected = None;
+        while let Some(play) = self.history.pop() {
+            self.game.undo(&play);
+            if let Play::Reveal(_) = play {
+                continue;
+            }
+            return;
+        }
+        self.message = "Nothing to undo";
+    }
+
+    fn moves(&self) -> usize {
+        self.history.iter().filter(|play| match **play {
+            Play::Reveal(_) => false,
+            _ => true,
+        }).count()
+    }
+
+    fn render<S: Screen>(&self, t: &mut S) {
+        let top = S::rows(Height::Card) + S::rows(Height::Edge);
+        t.move_to(0, 0);
+        self.game.render(t);
+
+        let bottom = TABLEAUX.iter().map(|&tableau| {
+            let pile = self.game.tableau(tableau);
+            let cascade: u16 = pile.vec.iter().rev().skip(1).map(|face| {
+                S::rows(if face.is_up() { Height::Border } else { Height::Edge })
+            }).sum();
+            top + cascade + S::rows(Height::Card)
+        }).max().unwrap();
+
+        let markers = self.selected.map(|target| (target, S::SELECTED)).into_iter()
+            .chain(Some((self.cursor, S::CURSOR)));
+        for (target, marker) in markers {
+            let row = match target {
+                Spot::Tableau(_) => bottom,
+                _ => top - S::rows(Height::Edge),
+            };
+            let column = target.column() * (S::columns(Width::Card) + S::columns(Width::Edge));
+            t.move_to(column + S::columns(Width::HalfCard), row);
+            t.text(marker);
+        }
+
+        let draw = match self.game.draw() {
+            Draw::One => "one",
+            Draw::Three => "three",
+        };
+        t.move_to(0, bottom + 2);
+        t.text(&format!(
+            "Seed {}  Draw {}  Moves {}  {}",
+            self.game.seed(),
+            draw,
+            self.moves(),
+            self.message,
+        ));
+        t.move_to(0, bottom + 3);
+        t.text("arrows move  space select  enter auto  esc cancel  d draw  r redeal  u undo  n new  q quit");
+    }
+}
+
+/// Canvas which can also write text, measured in character cells.
+trait Screen: Canvas {
+    const CURSOR: &'static str;
+    const SELECTED: &'static str;
+
+    fn columns(width: Width) -> u16;
+    fn rows(height: Height) -> u16;
+    fn move_to(&mut self, column: u16, row: u16);
+    fn text(&mut self, text: &str);
+}
+
+impl<W: Write> Screen for Terminal<W> {
+    const CURSOR: &'static str = "▲";
+    const SELECTED: &'static str = "●";
+
+    fn columns(width: Width) -> u16 {
+        terminal::columns(width)
+    }
+
+    fn rows(height: Height) -> u16 {
+        terminal::rows(height)
+    }
+
+    fn move_to(&mut self, column: u16, row: u16) {
+        Terminal::move_to(self, column, row);
+    }
+
+    fn text(&mut self, text: &str) {
+        Terminal::text(self, text);
+    }
+}
+
+impl Screen for Ascii {
+    const CURSOR: &'static str = "^";
+    const SELECTED: &'static str = "*";
+
+    fn columns(width: Width) -> u16 {
+        ascii::columns(width)
+    }
+
+    fn rows(height: Height) -> u16 {
+        ascii::rows(height)
+    }
+
+    fn move_to(&mut self, column: u16, row: u16) {
+        Ascii::move_to(self, column, row);
+    }
+
+    fn text(&mut self, text: &str) {
+        Ascii::text(self, text);
+    }
+}
+
+/// Terminal raw mode, restored on drop.
+#[derive(Debug)]
+struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        let output = Command::new("stty")
+            .arg("-g")
+            .stdin(Stdio::inherit())
+            .output()
+            .map_err(missing)?;
+        if !output.status.success() {
+            return Err(io::Error::other("stdin is not a terminal"));
+        }
+        let saved = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+        stty(&["raw", "-echo"])?;
+        Ok(RawMode { saved: saved })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+fn stty(args: &[&str]) -> io::Result<()> {
+    let status = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .status()
+        .map_err(missing)?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other("stty failed"))
+    }
+}
+
+/// Explains a failure to run `stty`, which raw mode needs.
+fn missing(error: io::Error) -> io::Error {
+    if error.kind() == io::ErrorKind::NotFound {
+        io::Error::new(error.kind(), "stty not found; patience needs a Unix terminal")
+    } else {
+        error
+    }
+}
+
+/// Renders a frame, in ASCII or with Unicode and colors.
+fn frame(app: &App, ascii: bool) -> io::Result<Vec<u8>> {
+    if ascii {
+        let mut canvas = Ascii::new();
+        app.render(&mut canvas);
+        let text = canvas.to_string().replace('\n', "\r\n");
+        Ok(format!("\x1b[2J\x1b[H{}", text).into_bytes())
+    } else {
+        let mut canvas = Terminal::new(Vec::new());
+        canvas.clear();
+        app.render(&mut canvas);
+        canvas.into_inner()
+    }
+}
+
+fn run(mut app: App, ascii: bool) -> io::Result<()> {
+    let _raw = RawMode::enable()?;
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut stdin = stdin.lock();
+    let mut stdout = stdout.lock();
+    stdout.write_all(b"\x1b[?25l")?;
+
+    let mut buf = [0; 8];
+    'read: loop {
+        stdout.write_all(&frame(&app, ascii)?)?;
+        stdout.flush()?;
+
+        let n = stdin.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        let mut bytes = &buf[..n];
+        while !bytes.is_empty() {
+            let (key, len) = Key::parse(bytes);
+            bytes = &bytes[len..];
+            if let Some(key) = key {
+                if !app.key(key) {
+                    break 'read;
+                }
+            }
+        }
+    }
+
+    stdout.write_all(b"\x1b[?25h\x1b[2J\x1b[H")?;
+    stdout.flush()
+}
+
+fn main() {
+    let mut draw = Draw::One;
+    let mut seed = None;
+    let mut ascii = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "-3" | "--three" => draw = Draw::Three,
+            "-a" | "--ascii" => ascii = true,
+            arg => match arg.parse() {
+                Ok(n) => seed = Some(n),
+                Err(_) => {
+                    eprintln!("usage: patience [--three] [--ascii] [SEED]");
+                    process::exit(2);
+                },
+            },
+        }
+    }
+
+    let app = App::new(draw, seed.unwrap_or_else(rand::random));
+    if let Err(error) = run(app, ascii) {
+        eprintln!("patience: {}", error);
+        process::exit(1);
+    }
+}
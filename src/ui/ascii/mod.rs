@@ -0,0 +1,134 @@
+//! Plain-ASCII canvas.
+
+use std::fmt::{self, Display, Formatter};
+
+use card::Face;
+use ui::{Canvas, Width, Height};
+
+/// Returns the number of character columns in a width.
+pub fn columns(width: Width) -> u16 {
+    match width {
+        Width::Edge => 1,
+        Width::Border => 3,
+        Width::Pip => 1,
+        Width::HalfCard => 3,
+        Width::Card => 6,
+    }
+}
+
+/// Returns the number of character rows in a height.
+pub fn rows(height: Height) -> u16 {
+    match height {
+        Height::Edge => 1,
+        Height::Border => 2,
+        Height::Pip => 1,
+        Height::HalfCard => 2,
+        Height::Card => 4,
+    }
+}
+
+/// Canvas drawing cards as ASCII art into a grid of characters.
+#[derive(Debug, Clone, Default)]
+pub struct Ascii {
+    column: u16,
+    row: u16,
+    stack: Vec<(u16, u16)>,
+    lines: Vec<Vec<char>>,
+}
+
+impl Ascii {
+    /// Creates an empty grid positioned at the top-left.
+    pub fn new() -> Self {
+        Ascii::default()
+    }
+
+    /// Returns the current column and row.
+    pub fn position(&self) -> (u16, u16) {
+        (self.column, self.row)
+    }
+
+    /// Moves to a column and row.
+    pub fn move_to(&mut self, column: u16, row: u16) {
+        self.column = column;
+        self.row = row;
+    }
+
+    /// Writes text at the current position.
+    pub fn text(&mut self, text: &str) {
+        let (column, row) = (self.column, self.row);
+        self.line(column, row, text);
+    }
+
+    fn line(&mut self, column: u16, row: u16, s: &str) {
+        let (column, row) = (column as usize, row as usize);
+        if self.lines.len() <= row {
+            self.lines.resize(row + 1, Vec::new());
+        }
+        let line = &mut self.lines[row];
+        for (i, c) in s.chars().enumerate() {
+            if line.len() <= column + i {
+                line.resize(column + i + 1, ' ');
+            }
+            line[column + i] = c;
+        }
+    }
+}
+
+impl Canvas for Ascii {
+    fn right(&mut self, width: Width) {
+        self.column += columns(width);
+    }
+
+    fn down(&mut self, height: Height) {
+        self.row += rows(height);
+    }
+
+    fn push(&mut self) {
+        self.stack.push((self.column, self.row));
+    }
+
+    fn pop(&mut self) {
+        let (column, row) = self.stack.pop().expect("pop without push");
+        self.move_to(column, row);
+    }
+
+    fn draw(&mut self, face: Option<Face>) {
+        let (column, row) = (self.column, self.row);
+        let lines = match face {
+            None => [
+                String::from("+    +"),
+                String::from("      "),
+                String::from("      "),
+                String::from("+    +"),
+            ],
+
+            Some(face) => {
+                let [a, b] = face.ascii();
+                let (top, bottom) = if face.is_up() {
+                    (format!("|{}{}  |", a, b), format!("|  {}{}|", a, b))
+                } else {
+                    (String::from("|####|"), String::from("|####|"))
+                };
+                [String::from("+----+"), top, bottom, String::from("+----+")]
+            },
+        };
+
+        for (i, line) in lines.iter().enumerate() {
+            self.line(column, row + i as u16, line);
+        }
+    }
+}
+
+/// Lines of the grid, without trailing spaces.
+impl Display for Ascii {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for line in &self.lines {
+            let line: String = line.iter().collect();
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,71 @@
+use card::Face;
+use ui::{Canvas, Width, Height};
+use ui::ascii::Ascii;
+
+fn draw(face: Option<Face>) -> String {
+    let mut ascii = Ascii::new();
+    ascii.draw(face);
+    ascii.to_string()
+}
+
+#[test]
+fn empty() {
+    assert_eq!(draw(None), "+    +\n\n\n+    +\n");
+}
+
+#[test]
+fn down() {
+    assert_eq!(draw(Some(Face::Down(card!(H A)))), "+----+\n|####|\n|####|\n+----+\n");
+}
+
+#[test]
+fn up() {
+    assert_eq!(draw(Some(Face::Up(card!(H 10)))), "+----+\n|TH  |\n|  TH|\n+----+\n");
+}
+
+#[test]
+fn cascade() {
+    let mut ascii = Ascii::new();
+    ascii.draw(Some(Face::Down(card!(S 2))));
+    ascii.down(Height::Edge);
+    ascii.draw(Some(Face::Up(card!(C Q))));
+    ascii.down(Height::Border);
+    ascii.draw(Some(Face::Up(card!(D J))));
+    assert_eq!(
+        ascii.to_string(),
+        "+----+\n\
+         +----+\n\
+         |QC  |\n\
+         +----+\n\
+         |JD  |\n\
+         |  JD|\n\
+         +----+\n"
+    );
+}
+
+#[test]
+fn side_by_side() {
+    let mut ascii = Ascii::new();
+    ascii.push();
+    ascii.right(Width::Card);
+    ascii.right(Width::Edge);
+    ascii.draw(Some(Face::Up(card!(S A))));
+    ascii.pop();
+    ascii.draw(None);
+    assert_eq!(ascii.position(), (0, 0));
+    assert_eq!(
+        ascii.to_string(),
+        "+    + +----+\n\
+         \x20      |AS  |\n\
+         \x20      |  AS|\n\
+         +    + +----+\n"
+    );
+}
+
+#[test]
+fn text() {
+    let mut ascii = Ascii::new();
+    ascii.move_to(2, 1);
+    ascii.text("Won!");
+    assert_eq!(ascii.to_string(), "\n  Won!\n");
+}
@@ -0,0 +1,135 @@
+//! Hit-testing.
+
+use std::cell::RefCell;
+
+use card::Face;
+use ui::{Canvas, Width, Height, Render};
+
+/// Rectangle covered by a draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub struct Region<T> {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub target: T,
+}
+
+impl<T> Region<T> {
+    /// Returns true if the region contains a point.
+    pub fn contains(&self, x: u32, y: u32) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+}
+
+/// Canvas recording the region and target of each draw.
+///
+/// Widths and heights are measured by the functions given to `new`, so that points can be
+/// expressed in the units of a particular front end.
+#[derive(Debug)]
+pub struct Hits<T> {
+    width: fn(Width) -> u32,
+    height: fn(Height) -> u32,
+    position: (u32, u32),
+    stack: Vec<(u32, u32)>,
+    target: Option<T>,
+    regions: Vec<Region<T>>,
+}
+
+impl<T: Copy> Hits<T> {
+    /// Creates an empty canvas measuring with the given functions.
+    pub fn new(width: fn(Width) -> u32, height: fn(Height) -> u32) -> Self {
+        Hits {
+            width: width,
+            height: height,
+            position: (0, 0),
+            stack: Vec::new(),
+            target: None,
+            regions: Vec::new(),
+        }
+    }
+
+    /// Records the regions of a game.
+    pub fn of<G>(game: &G, width: fn(Width) -> u32, height: fn(Height) -> u32) -> Self
+    where G: Render<Target=T> {
+        let hits = RefCell::new(Hits::new(width, height));
+        game.render_with_targets(&mut Shared(&hits), &mut |target| {
+            hits.borrow_mut().target(target);
+        });
+        hits.into_inner()
+    }
+
+    /// Sets the target of the draws that follow.
+    pub fn target(&mut self, target: T) {
+        self.target = Some(target);
+    }
+
+    /// Returns the recorded regions, in drawing order.
+    pub fn regions(&self) -> &[Region<T>] {
+        &self.regions
+    }
+
+    /// Returns the target of the topmost region containing a point.
+    pub fn hit(&self, x: u32, y: u32) -> Option<T> {
+        self.regions.iter()
+            .rev()
+            .find(|region| region.contains(x, y))
+            .map(|region| region.target)
+    }
+}
+
+impl<T: Copy> Canvas for Hits<T> {
+    fn right(&mut self, width: Width) {
+        self.position.0 += (self.width)(width);
+    }
+
+    fn down(&mut self, height: Height) {
+        self.position.1 += (self.height)(height);
+    }
+
+    fn push(&mut self) {
+        self.stack.push(self.position);
+    }
+
+    fn pop(&mut self) {
+        self.position = self.stack.pop().expect("pop without push");
+    }
+
+    fn draw(&mut self, _: Option<Face>) {
+        if let Some(target) = self.target {
+            self.regions.push(Region {
+                x: self.position.0,
+                y: self.position.1,
+                width: (self.width)(Width::Card),
+                height: (self.height)(Height::Card),
+                target: target,
+            });
+        }
+    }
+}
+
+/// Canvas shared with the target callback during rendering.
+struct Shared<'a, C: 'a>(&'a RefCell<C>);
+
+impl<'a, C: Canvas> Canvas for Shared<'a, C> {
+    fn right(&mut self, width: Width) {
+        self.0.borrow_mut().right(width);
+    }
+
+    fn down(&mut self, height: Height) {
+        self.0.borrow_mut().down(height);
+    }
+
+    fn push(&mut self) {
+        self.0.borrow_mut().push();
+    }
+
+    fn pop(&mut self) {
+        self.0.borrow_mut().pop();
+    }
+
+    fn draw(&mut self, face: Option<Face>) {
+        self.0.borrow_mut().draw(face);
+    }
+}
@@ -57,3 +57,30 @@ pub trait Canvas {
     /// Draws a card face or an empty cell.
     fn draw(&mut self, face: Option<Face>);
 }
+
+/// Game which can be drawn on a canvas.
+pub trait Render {
+    /// Pile or card targeted by a draw.
+    type Target: Copy;
+
+    /// Draws the game with its top-left at the current position, leaving the position unchanged.
+    fn render<C: Canvas>(&self, canvas: &mut C) {
+        self.render_with_targets(canvas, &mut |_| {});
+    }
+
+    /// Draws the game as `render`, calling `on_target` with the target of the draws that follow,
+    /// for hit-testing.
+    fn render_with_targets<C: Canvas>(
+        &self,
+        canvas: &mut C,
+        on_target: &mut dyn FnMut(Self::Target),
+    );
+}
+
+pub mod ascii;
+pub mod hit;
+pub mod svg;
+pub mod terminal;
+
+#[cfg(test)]
+pub mod record;
@@ -0,0 +1,43 @@
+//! Canvas recording draws, for testing layouts.
+
+use card::Face;
+use ui::{Canvas, Width, Height};
+use ui::terminal::{columns, rows};
+
+/// Canvas recording the terminal cell position of each draw.
+#[derive(Debug, Default)]
+pub struct Record {
+    position: (u16, u16),
+    stack: Vec<(u16, u16)>,
+    /// Column, row and face of each draw.
+    pub draws: Vec<(u16, u16, Option<Face>)>,
+}
+
+impl Record {
+    /// Returns the current column and row.
+    pub fn position(&self) -> (u16, u16) {
+        self.position
+    }
+}
+
+impl Canvas for Record {
+    fn right(&mut self, width: Width) {
+        self.position.0 += columns(width);
+    }
+
+    fn down(&mut self, height: Height) {
+        self.position.1 += rows(height);
+    }
+
+    fn push(&mut self) {
+        self.stack.push(self.position);
+    }
+
+    fn pop(&mut self) {
+        self.position = self.stack.pop().expect("pop without push");
+    }
+
+    fn draw(&mut self, face: Option<Face>) {
+        self.draws.push((self.position.0, self.position.1, face));
+    }
+}
@@ -0,0 +1,223 @@
+//! SVG canvas.
+
+use std::fmt::{self, Display, Formatter};
+
+use card::{Color, Rank, Card, Face};
+use ui::{Canvas, Width, Height};
+
+/// Returns the number of pixels in a width.
+pub fn width(width: Width) -> u32 {
+    match width {
+        Width::Edge => 6,
+        Width::Border => 20,
+        Width::Pip => 12,
+        Width::HalfCard => 30,
+        Width::Card => 60,
+    }
+}
+
+/// Returns the number of pixels in a height.
+pub fn height(height: Height) -> u32 {
+    match height {
+        Height::Edge => 6,
+        Height::Border => 20,
+        Height::Pip => 12,
+        Height::HalfCard => 42,
+        Height::Card => 84,
+    }
+}
+
+const STYLE: &str = "\
+.card{fill:#fff;stroke:#000}\
+.back{fill:#247;stroke:#000}\
+.inset{fill:none;stroke:#8ad}\
+.empty{fill:none;stroke:#888;stroke-dasharray:4 3}\
+.red{fill:#c00}\
+.black{fill:#000}\
+text{font-family:sans-serif;text-anchor:middle;dominant-baseline:central}\
+.index{font-size:11px}\
+.pip{font-size:12px}\
+.ace{font-size:36px}\
+.face{font-size:28px;font-weight:bold}";
+
+/// Pip columns, in pips from the center, and rows, in thirds of a pip from the center, by rank.
+fn pips(rank: Rank) -> &'static [(i32, i32)] {
+    match rank {
+        Rank::Ace => &[(0, 0)],
+        Rank::Two => &[(0, -6), (0, 6)],
+        Rank::Three => &[(0, -6), (0, 0), (0, 6)],
+        Rank::Four => &[(-1, -6), (1, -6), (-1, 6), (1, 6)],
+        Rank::Five => &[(-1, -6), (1, -6), (0, 0), (-1, 6), (1, 6)],
+        Rank::Six => &[(-1, -6), (1, -6), (-1, 0), (1, 0), (-1, 6), (1, 6)],
+        Rank::Seven => &[(-1, -6), (1, -6), (0, -3), (-1, 0), (1, 0), (-1, 6), (1, 6)],
+        Rank::Eight => &[
+            (-1, -6), (1, -6), (0, -3), (-1, 0), (1, 0), (0, 3), (-1, 6), (1, 6),
+        ],
+        Rank::Nine => &[
+            (-1, -6), (1, -6), (-1, -2), (1, -2), (0, 0), (-1, 2), (1, 2), (-1, 6), (1, 6),
+        ],
+        Rank::Ten => &[
+            (-1, -6), (1, -6), (0, -4), (-1, -2), (1, -2), (-1, 2), (1, 2), (0, 4), (-1, 6),
+            (1, 6),
+        ],
+        Rank::Jack | Rank::Queen | Rank::King => &[],
+    }
+}
+
+fn label(rank: Rank) -> &'static str {
+    match rank {
+        Rank::Ace => "A",
+        Rank::Two => "2",
+        Rank::Three => "3",
+        Rank::Four => "4",
+        Rank::Five => "5",
+        Rank::Six => "6",
+        Rank::Seven => "7",
+        Rank::Eight => "8",
+        Rank::Nine => "9",
+        Rank::Ten => "10",
+        Rank::Jack => "J",
+        Rank::Queen => "Q",
+        Rank::King => "K",
+    }
+}
+
+/// Canvas building a standalone SVG document, sized to fit everything drawn.
+#[derive(Debug, Clone, Default)]
+pub struct Svg {
+    position: (u32, u32),
+    stack: Vec<(u32, u32)>,
+    size: (u32, u32),
+    body: String,
+}
+
+impl Svg {
+    /// Creates an empty document.
+    pub fn new() -> Self {
+        Svg::default()
+    }
+
+    /// Returns the width and height of the document.
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn card(&mut self, card: Card) {
+        let class = match card.suit.color() {
+            Color::Red => "red",
+            Color::Black => "black",
+        };
+        let suit: char = card.suit.into();
+        let (w, h) = (width(Width::Card), height(Height::Card));
+        let (cx, cy) = (width(Width::HalfCard), height(Height::HalfCard));
+        let (pw, ph) = (width(Width::Pip) as i32, height(Height::Pip) as i32);
+        let index = format!("{}{}", label(card.rank), suit);
+
+        self.body += &format!(r#"<rect width="{}" height="{}" rx="5" class="card"/>"#, w, h);
+        self.body += &format!(
+            r#"<text x="10" y="9" class="index {}">{}</text>"#,
+            class, index,
+        );
+        self.body += &format!(
+            r#"<text x="10" y="9" class="index {}" transform="rotate(180 {} {})">{}</text>"#,
+            class, cx, cy, index,
+        );
+
+        if card.rank.is_face() {
+            let (bw, bh) = (width(Width::Border), height(Height::Border));
+            self.body += &format!(
+                r#"<rect x="{}" y="{}" width="{}" height="{}" class="inset"/>"#,
+                bw, bh, w - 2 * bw, h - 2 * bh,
+            );
+            self.body += &format!(
+                r#"<text x="{}" y="{}" class="face {}">{}</text>"#,
+                cx, cy as i32 - ph / 2, class, label(card.rank),
+            );
+            self.body += &format!(
+                r#"<text x="{}" y="{}" class="pip {}">{}</text>"#,
+                cx, cy as i32 + ph, class, suit,
+            );
+        } else if card.rank == Rank::Ace {
+            self.body += &format!(
+                r#"<text x="{}" y="{}" class="ace {}">{}</text>"#,
+                cx, cy, class, suit,
+            );
+        } else {
+            for &(column, row) in pips(card.rank) {
+                let x = cx as i32 + column * pw;
+                let y = cy as i32 + row * ph / 3;
+                if row > 0 {
+                    self.body += &format!(
+                        r#"<text x="{}" y="{}" class="pip {}" transform="rotate(180 {} {})">{}</text>"#,
+                        x, y, class, x, y, suit,
+                    );
+                } else {
+                    self.body += &format!(
+                        r#"<text x="{}" y="{}" class="pip {}">{}</text>"#,
+                        x, y, class, suit,
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl Canvas for Svg {
+    fn right(&mut self, w: Width) {
+        self.position.0 += width(w);
+    }
+
+    fn down(&mut self, h: Height) {
+        self.position.1 += height(h);
+    }
+
+    fn push(&mut self) {
+        self.stack.push(self.position);
+    }
+
+    fn pop(&mut self) {
+        self.position = self.stack.pop().expect("pop without push");
+    }
+
+    fn draw(&mut self, face: Option<Face>) {
+        let (x, y) = self.position;
+        let (w, h) = (width(Width::Card), height(Height::Card));
+        self.size.0 = self.size.0.max(x + w);
+        self.size.1 = self.size.1.max(y + h);
+
+        self.body += &format!(r#"<g transform="translate({} {})">"#, x, y);
+        match face {
+            None => {
+                self.body += &format!(r#"<rect width="{}" height="{}" rx="5" class="empty"/>"#, w, h);
+            },
+            Some(Face::Down(_)) => {
+                let (ew, eh) = (width(Width::Edge), height(Height::Edge));
+                self.body += &format!(r#"<rect width="{}" height="{}" rx="5" class="back"/>"#, w, h);
+                self.body += &format!(
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" rx="3" class="inset"/>"#,
+                    ew / 2, eh / 2, w - ew, h - eh,
+                );
+            },
+            Some(Face::Up(card)) => self.card(card),
+        }
+        self.body += "</g>\n";
+    }
+}
+
+/// Standalone SVG document.
+impl Display for Svg {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (w, h) = self.size;
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">"#,
+            w, h, w, h,
+        )?;
+        writeln!(f, "<style>{}</style>", STYLE)?;
+        f.write_str(&self.body)?;
+        writeln!(f, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests;
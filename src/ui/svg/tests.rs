@@ -0,0 +1,74 @@
+use std::env;
+use std::fs;
+
+use card::Face;
+use game::Game;
+use game::klondike::{Klondike, Draw, Play};
+use ui::{Canvas, Width, Height, Render};
+use ui::svg::Svg;
+
+/// Compares a document with a golden file, rewriting it if `UPDATE_GOLDEN` is set.
+fn golden(name: &str, svg: &Svg) {
+    let path = format!("{}/src/ui/svg/golden/{}.svg", env!("CARGO_MANIFEST_DIR"), name);
+    let actual = svg.to_string();
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, &actual).unwrap();
+    }
+    let expected = fs::read_to_string(&path).unwrap();
+    assert!(actual == expected, "{} differs from golden file; set UPDATE_GOLDEN to update", path);
+}
+
+fn draw(face: Option<Face>) -> Svg {
+    let mut svg = Svg::new();
+    svg.draw(face);
+    svg
+}
+
+#[test]
+fn empty() {
+    golden("empty", &draw(None));
+}
+
+#[test]
+fn back() {
+    golden("back", &draw(Some(Face::Down(card!(S A)))));
+}
+
+#[test]
+fn ace() {
+    golden("ace", &draw(Some(Face::Up(card!(H A)))));
+}
+
+#[test]
+fn seven() {
+    golden("seven", &draw(Some(Face::Up(card!(C 7)))));
+}
+
+#[test]
+fn ten() {
+    golden("ten", &draw(Some(Face::Up(card!(D 10)))));
+}
+
+#[test]
+fn king() {
+    golden("king", &draw(Some(Face::Up(card!(S K)))));
+}
+
+#[test]
+fn klondike() {
+    let mut game = Klondike::with_seed(Draw::Three, 1);
+    game.deal();
+    game.play(&Play::Draw);
+    let mut svg = Svg::new();
+    game.render(&mut svg);
+    golden("klondike", &svg);
+}
+
+#[test]
+fn size() {
+    let mut svg = Svg::new();
+    svg.right(Width::Card);
+    svg.down(Height::Border);
+    svg.draw(None);
+    assert_eq!(svg.size(), (120, 104));
+}
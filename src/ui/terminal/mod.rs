@@ -0,0 +1,167 @@
+//! ANSI terminal canvas.
+
+use std::io::{self, Write};
+
+use card::{Color, Face};
+use ui::{Canvas, Width, Height};
+
+/// Returns the number of character columns in a width.
+pub fn columns(width: Width) -> u16 {
+    match width {
+        Width::Edge => 1,
+        Width::Border => 3,
+        Width::Pip => 2,
+        Width::HalfCard => 3,
+        Width::Card => 7,
+    }
+}
+
+/// Returns the number of character rows in a height.
+pub fn rows(height: Height) -> u16 {
+    match height {
+        Height::Edge => 1,
+        Height::Border => 2,
+        Height::Pip => 1,
+        Height::HalfCard => 2,
+        Height::Card => 5,
+    }
+}
+
+const RED: &str = "\x1b[31m";
+const BLUE: &str = "\x1b[34m";
+const RESET: &str = "\x1b[0m";
+
+/// Canvas drawing cards with box-drawing characters and ANSI escape sequences.
+///
+/// Positions are character cells from the top-left of the terminal. Write errors are deferred
+/// until `into_inner`.
+#[derive(Debug)]
+pub struct Terminal<W: Write> {
+    writer: W,
+    column: u16,
+    row: u16,
+    stack: Vec<(u16, u16)>,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> Terminal<W> {
+    /// Creates a canvas positioned at the top-left.
+    pub fn new(writer: W) -> Self {
+        Terminal {
+            writer: writer,
+            column: 0,
+            row: 0,
+            stack: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Returns the current column and row.
+    pub fn position(&self) -> (u16, u16) {
+        (self.column, self.row)
+    }
+
+    /// Moves to a column and row.
+    pub fn move_to(&mut self, column: u16, row: u16) {
+        self.column = column;
+        self.row = row;
+    }
+
+    /// Writes text at the current position.
+    pub fn text(&mut self, text: &str) {
+        let (column, row) = (self.column, self.row);
+        self.line(column, row, text);
+    }
+
+    /// Clears the terminal.
+    pub fn clear(&mut self) {
+        self.write("\x1b[2J");
+    }
+
+    /// Flushes the writer and returns it, or the first error that occurred.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn write(&mut self, s: &str) {
+        if self.error.is_none() {
+            if let Err(error) = self.writer.write_all(s.as_bytes()) {
+                self.error = Some(error);
+            }
+        }
+    }
+
+    fn line(&mut self, column: u16, row: u16, s: &str) {
+        let s = format!("\x1b[{};{}H{}", row + 1, column + 1, s);
+        self.write(&s);
+    }
+}
+
+impl<W: Write> Canvas for Terminal<W> {
+    fn right(&mut self, width: Width) {
+        self.column += columns(width);
+    }
+
+    fn down(&mut self, height: Height) {
+        self.row += rows(height);
+    }
+
+    fn push(&mut self) {
+        self.stack.push((self.column, self.row));
+    }
+
+    fn pop(&mut self) {
+        let (column, row) = self.stack.pop().expect("pop without push");
+        self.move_to(column, row);
+    }
+
+    fn draw(&mut self, face: Option<Face>) {
+        let (column, row) = (self.column, self.row);
+        let lines = match face {
+            None => [
+                String::from("┌╌╌╌╌╌┐"),
+                String::from("╎     ╎"),
+                String::from("╎     ╎"),
+                String::from("╎     ╎"),
+                String::from("└╌╌╌╌╌┘"),
+            ],
+
+            Some(Face::Down(_)) => {
+                let back = format!("│{}░░░░░{}│", BLUE, RESET);
+                [
+                    String::from("┌─────┐"),
+                    back.clone(),
+                    back.clone(),
+                    back,
+                    String::from("└─────┘"),
+                ]
+            },
+
+            Some(Face::Up(card)) => {
+                let chars: [char; 2] = card.into();
+                let index = match card.suit.color() {
+                    Color::Red => format!("{}{}{}{}", RED, chars[0], chars[1], RESET),
+                    Color::Black => format!("{}{}", chars[0], chars[1]),
+                };
+                [
+                    String::from("┌─────┐"),
+                    format!("│{}   │", index),
+                    String::from("│     │"),
+                    format!("│   {}│", index),
+                    String::from("└─────┘"),
+                ]
+            },
+        };
+
+        for (i, line) in lines.iter().enumerate() {
+            self.line(column, row + i as u16, line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,141 @@
+use std::io::{self, Write};
+
+use card::Face;
+use ui::{Canvas, Width, Height};
+use ui::terminal::Terminal;
+
+fn render<F>(f: F) -> String where F: FnOnce(&mut Terminal<Vec<u8>>) {
+    let mut terminal = Terminal::new(Vec::new());
+    f(&mut terminal);
+    String::from_utf8(terminal.into_inner().unwrap()).unwrap()
+}
+
+mod draw {
+    use super::render;
+    use card::Face;
+    use ui::Canvas;
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            render(|t| t.draw(None)),
+            "\x1b[1;1H┌╌╌╌╌╌┐\
+             \x1b[2;1H╎     ╎\
+             \x1b[3;1H╎     ╎\
+             \x1b[4;1H╎     ╎\
+             \x1b[5;1H└╌╌╌╌╌┘"
+        );
+    }
+
+    #[test]
+    fn down() {
+        assert_eq!(
+            render(|t| t.draw(Some(Face::Down(card!(H A))))),
+            "\x1b[1;1H┌─────┐\
+             \x1b[2;1H│\x1b[34m░░░░░\x1b[0m│\
+             \x1b[3;1H│\x1b[34m░░░░░\x1b[0m│\
+             \x1b[4;1H│\x1b[34m░░░░░\x1b[0m│\
+             \x1b[5;1H└─────┘"
+        );
+    }
+
+    #[test]
+    fn up_black() {
+        assert_eq!(
+            render(|t| t.draw(Some(Face::Up(card!(S 10))))),
+            "\x1b[1;1H┌─────┐\
+             \x1b[2;1H│♠T   │\
+             \x1b[3;1H│     │\
+             \x1b[4;1H│   ♠T│\
+             \x1b[5;1H└─────┘"
+        );
+    }
+
+    #[test]
+    fn up_red() {
+        assert_eq!(
+            render(|t| t.draw(Some(Face::Up(card!(D Q))))),
+            "\x1b[1;1H┌─────┐\
+             \x1b[2;1H│\x1b[31m♦Q\x1b[0m   │\
+             \x1b[3;1H│     │\
+             \x1b[4;1H│   \x1b[31m♦Q\x1b[0m│\
+             \x1b[5;1H└─────┘"
+        );
+    }
+}
+
+mod position {
+    use super::render;
+    use ui::{Canvas, Width, Height};
+    use ui::terminal::Terminal;
+
+    #[test]
+    fn right() {
+        let mut terminal = Terminal::new(Vec::new());
+        terminal.right(Width::Card);
+        terminal.right(Width::Edge);
+        assert_eq!(terminal.position(), (8, 0));
+    }
+
+    #[test]
+    fn down() {
+        let mut terminal = Terminal::new(Vec::new());
+        terminal.down(Height::Border);
+        terminal.down(Height::Edge);
+        assert_eq!(terminal.position(), (0, 3));
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut terminal = Terminal::new(Vec::new());
+        terminal.right(Width::Pip);
+        terminal.push();
+        terminal.down(Height::Card);
+        terminal.push();
+        terminal.right(Width::Card);
+        terminal.pop();
+        assert_eq!(terminal.position(), (2, 5));
+        terminal.pop();
+        assert_eq!(terminal.position(), (2, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn pop_empty() {
+        let mut terminal = Terminal::new(Vec::new());
+        terminal.pop();
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            render(|t| {
+                t.right(Width::Card);
+                t.down(Height::Card);
+                t.text("Won!");
+            }),
+            "\x1b[6;8HWon!"
+        );
+    }
+}
+
+struct Broken;
+
+impl Write for Broken {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("broken"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn deferred_error() {
+    let mut terminal = Terminal::new(Broken);
+    terminal.draw(Some(Face::Up(card!(H A))));
+    terminal.right(Width::Card);
+    terminal.down(Height::Card);
+    assert!(terminal.into_inner().is_err());
+}